@@ -0,0 +1,39 @@
+//! Runtime API of the assets pallet, for queries which RPC clients cannot answer from raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_core::U256;
+use sp_std::prelude::*;
+
+use snowbridge_core::AssetId;
+
+sp_api::decl_runtime_apis! {
+	pub trait AssetsApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Summed total issuance of the assets in a basket group, saturated on overflow,
+		/// together with whether the sum overflowed.
+		fn group_total_supply(group: u32) -> (U256, bool);
+
+		/// Up to `count` assets with their total issuance, starting from the asset at
+		/// position `start` in storage order.
+		fn export_assets_paged(start: u32, count: u32) -> Vec<(AssetId, U256)>;
+
+		/// Total issuance of an asset minus all of its balances on hold.
+		fn reducible_total_issuance(asset_id: AssetId) -> U256;
+
+		/// Amount which may still be minted before reaching the max supply, or `None` if the
+		/// asset is uncapped.
+		fn remaining_mintable(asset_id: AssetId) -> Option<U256>;
+
+		/// Free balance of `who` as of the end of `block`, or `None` if `block` predates the
+		/// retained checkpoints.
+		fn balance_at(asset_id: AssetId, who: AccountId, block: BlockNumber) -> Option<U256>;
+
+		/// The `n` accounts with the largest free balances of an asset, largest first.
+		fn largest_holders(asset_id: AssetId, n: u32) -> Vec<(AccountId, U256)>;
+	}
+}
@@ -5,8 +5,9 @@
 use super::*;
 
 use frame_system::RawOrigin;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, whitelist_account, impl_benchmark_test_suite};
 use sp_core::H160;
+use sp_runtime::traits::TrailingZeroInput;
 
 #[allow(unused_imports)]
 use crate::Module as Assets;
@@ -16,6 +17,30 @@ fn set_balance<T: Config>(asset_id: &AssetId, who: &T::AccountId, amount: &U256)
 	Balances::<T>::insert(asset_id, who, amount);
 }
 
+// Number of unrelated accounts holding the benchmarked asset, so that weights reflect
+// the trie depth of a realistically populated `Balances` prefix.
+const PREFILLED_ACCOUNTS: u32 = 5000;
+
+fn prefill_accounts<T: Config>(asset_id: &AssetId, count: u32) {
+	for i in 0 .. count {
+		let who: T::AccountId = account("prefilled", i, 0);
+		Balances::<T>::insert(asset_id, &who, U256::one());
+	}
+	TotalIssuance::mutate(asset_id, |total| *total = *total + U256::from(count));
+}
+
+fn transfer_origin<T: Config>() -> Result<(T::Origin, T::AccountId), &'static str> {
+	let origin = T::TransferOrigin::successful_origin();
+	let caller = T::TransferOrigin::ensure_origin(origin.clone())
+		.map_err(|_| "Failed to get the account of the transfer origin")?;
+	Ok((origin, caller))
+}
+
+fn hold_reason<T: Config>() -> Result<T::HoldReason, &'static str> {
+	T::HoldReason::decode(&mut TrailingZeroInput::new(&[][..]))
+		.map_err(|_| "Failed to decode a hold reason")
+}
+
 fn get_balance<T: Config>(asset_id: &AssetId, who: &T::AccountId) -> U256 {
 	Balances::<T>::get(asset_id, who)
 }
@@ -24,8 +49,10 @@ benchmarks! {
 	// Benchmark `transfer` extrinsic under worst case conditions, i.e. successful transfer:
 	// * `transfer` will substract amount from caller account
 	// * `transfer` will add amount to destination account
+	// * The asset is already held by `PREFILLED_ACCOUNTS` other accounts
 	transfer {
-		let caller: T::AccountId = whitelisted_caller();
+		let (origin, caller) = transfer_origin::<T>()?;
+		whitelist_account!(caller);
 		let initial_amount = U256::from_str_radix("1000000000000000000", 10).unwrap();
 		let transfer_amount = U256::from_str_radix("500000000000000000", 10).unwrap();
 		let token = AssetId::Token(H160::zero());
@@ -33,12 +60,422 @@ benchmarks! {
 		let dest_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(dest.clone());
 
 		set_balance::<T>(&token, &caller, &initial_amount);
+		prefill_accounts::<T>(&token, PREFILLED_ACCOUNTS);
 
-	}: _(RawOrigin::Signed(caller.clone()), token, dest_lookup, transfer_amount)
+	}: _(origin, token, dest_lookup, transfer_amount)
 	verify {
 		assert_eq!(get_balance::<T>(&token, &caller), initial_amount - transfer_amount);
 		assert_eq!(get_balance::<T>(&token, &dest), transfer_amount);
 	}
+
+	// Benchmark `transfer_multi` extrinsic under worst case conditions:
+	// * Every transfer succeeds, each of a different asset and to a different destination
+	transfer_multi {
+		let n in 1 .. T::MaxBatch::get();
+
+		let (origin, caller) = transfer_origin::<T>()?;
+		whitelist_account!(caller);
+		let amount = U256::from(1000);
+		let mut transfers = Vec::new();
+		for i in 0 .. n {
+			let token = AssetId::Token(H160::from_low_u64_be(i as u64));
+			let dest: T::AccountId = account("recipient", i, 0);
+			set_balance::<T>(&token, &caller, &amount);
+			transfers.push((token, T::Lookup::unlookup(dest), amount));
+		}
+		let transfers = BoundedVec::<_, T::MaxBatch>::try_from(transfers)
+			.map_err(|_| "Failed to bound the batch of transfers")?;
+
+	}: _(origin, transfers)
+	verify {
+		let dest: T::AccountId = account("recipient", 0, 0);
+		assert_eq!(get_balance::<T>(&AssetId::Token(H160::zero()), &dest), amount);
+	}
+
+	// Benchmark `transfer` extrinsic for a zero amount, which returns before touching storage.
+	transfer_noop {
+		let (origin, caller) = transfer_origin::<T>()?;
+		whitelist_account!(caller);
+		let token = AssetId::Token(H160::zero());
+		let dest: T::AccountId = account("recipient", 0, 0);
+		let dest_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(dest.clone());
+
+	}: transfer(origin, token, dest_lookup, U256::zero())
+	verify {
+		assert_eq!(get_balance::<T>(&token, &dest), U256::zero());
+	}
+
+	// Benchmark `set_rate_limit` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_rate_limit {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let window_blocks: T::BlockNumber = 100u32.into();
+		let max_amount = U256::from(1000);
+
+	}: _(authorized_origin, token, Some((window_blocks, max_amount)))
+	verify {
+		assert!(RateLimits::<T>::contains_key(token));
+	}
+
+	// Benchmark `set_freeze` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * A nonzero amount is frozen
+	set_freeze {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let who: T::AccountId = account("holder", 0, 0);
+		let who_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(who.clone());
+		let amount = U256::from(1000);
+
+	}: _(authorized_origin, token, who_lookup, amount, 0)
+	verify {
+		assert_eq!(Frozen::<T>::get(&token, &who), amount);
+		assert_eq!(FreezeNonces::<T>::get(&token, &who), 1);
+	}
+
+	// Benchmark `set_max_supply` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * A cap is set, which requires reading the total issuance
+	set_max_supply {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let max = U256::from(1000);
+		TotalIssuance::insert(&token, U256::from(500));
+
+	}: _(authorized_origin, token, Some(max))
+	verify {
+		assert_eq!(MaxSupply::get(&token), Some(max));
+	}
+
+	// Benchmark `transfer_and_burn` extrinsic under worst case conditions:
+	// * Both the transfer and the burn succeed, on two different assets
+	transfer_and_burn {
+		let who: T::AccountId = account("debtor", 0, 0);
+		let who_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(who.clone());
+		let dest: T::AccountId = account("recipient", 0, 0);
+		let dest_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(dest.clone());
+		let collateral = AssetId::Token(H160::repeat_byte(1));
+		let debt = AssetId::Token(H160::repeat_byte(2));
+		let amount = U256::from(1000);
+
+		set_balance::<T>(&collateral, &who, &amount);
+		set_balance::<T>(&debt, &who, &amount);
+
+	}: _(RawOrigin::Root, who_lookup, (collateral, dest_lookup, amount), (debt, amount))
+	verify {
+		assert_eq!(get_balance::<T>(&collateral, &dest), amount);
+		assert_eq!(TotalIssuance::get(&debt), U256::zero());
+	}
+
+	// Benchmark `clear_account` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * A zero balance entry exists and is removed, along with a freeze and its nonce
+	clear_account {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let who: T::AccountId = account("holder", 0, 0);
+		let who_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(who.clone());
+		Balances::<T>::insert(&token, &who, U256::zero());
+		Frozen::<T>::insert(&token, &who, U256::one());
+		FreezeNonces::<T>::insert(&token, &who, 1);
+
+	}: _(authorized_origin, token, who_lookup)
+	verify {
+		assert!(!Balances::<T>::contains_key(&token, &who));
+	}
+
+	// Benchmark `mint` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals MintOrigin
+	// * The beneficiary already holds the asset, which has a maximum supply
+	// * The asset is already held by `PREFILLED_ACCOUNTS` other accounts
+	mint {
+		let authorized_origin = match T::MintOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let beneficiary_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(beneficiary.clone());
+		let amount = U256::from(1000);
+		set_balance::<T>(&token, &beneficiary, &amount);
+		prefill_accounts::<T>(&token, PREFILLED_ACCOUNTS);
+		MaxSupply::insert(&token, U256::MAX);
+
+	}: _(authorized_origin, token, beneficiary_lookup, amount)
+	verify {
+		assert_eq!(get_balance::<T>(&token, &beneficiary), amount * 2);
+	}
+
+	// Benchmark `set_global_pause` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	set_global_pause {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+
+	}: _(authorized_origin, true)
+	verify {
+		assert!(GlobalPaused::get());
+	}
+
+	// Benchmark `mint_batch` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals MintOrigin
+	// * Every mint succeeds, each to a different beneficiary
+	// * The asset has a max supply, which is checked on every mint
+	mint_batch {
+		let n in 1 .. T::MaxBatch::get();
+
+		let authorized_origin = match T::MintOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let amount = U256::from(1000);
+		let mut recipients = Vec::new();
+		for i in 0 .. n {
+			let beneficiary: T::AccountId = account("beneficiary", i, 0);
+			recipients.push((T::Lookup::unlookup(beneficiary), amount));
+		}
+		let recipients = BoundedVec::<_, T::MaxBatch>::try_from(recipients)
+			.map_err(|_| "Failed to bound the batch of recipients")?;
+		MaxSupply::insert(&token, U256::MAX);
+
+	}: _(authorized_origin, token, recipients)
+	verify {
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		assert_eq!(get_balance::<T>(&token, &beneficiary), amount);
+		assert_eq!(Assets::<T>::total_issuance(&token), amount * U256::from(n));
+	}
+
+	// Benchmark `pause_asset` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	pause_asset {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let until: T::BlockNumber = 10u32.into();
+
+	}: _(authorized_origin, token, Some(until))
+	verify {
+		assert_eq!(AssetPauses::<T>::get(&token), Some(Some(until)));
+	}
+
+	// Benchmark `unpause_asset` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals UpdateOrigin
+	// * The asset is paused, so the pause is removed
+	unpause_asset {
+		let authorized_origin = match T::UpdateOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		AssetPauses::<T>::insert(&token, None::<T::BlockNumber>);
+
+	}: _(authorized_origin, token)
+	verify {
+		assert!(!AssetPauses::<T>::contains_key(&token));
+	}
+
+	// Benchmark `mint_with_nonce` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals MintOrigin
+	// * The nonce is fresh, so the mint is applied and the nonce recorded
+	mint_with_nonce {
+		let authorized_origin = match T::MintOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let beneficiary_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(beneficiary.clone());
+		let amount = U256::from(1000);
+		set_balance::<T>(&token, &beneficiary, &amount);
+		MaxSupply::insert(&token, U256::MAX);
+
+	}: _(authorized_origin, token, beneficiary_lookup, amount, 1)
+	verify {
+		assert!(ProcessedNonces::contains_key(&token, 1));
+	}
+
+	// Benchmark `can_mint` under both branches, selected by `k`:
+	// * 0: The asset was never issued
+	// * 1: The asset is already issued and has a maximum supply to check against
+	can_mint {
+		let k in 0 .. 1;
+
+		let token = AssetId::Token(H160::zero());
+		let who: T::AccountId = account("holder", 0, 0);
+		let amount = U256::from(1000);
+		if k == 1 {
+			set_balance::<T>(&token, &who, &amount);
+			MaxSupply::insert(&token, U256::MAX);
+		}
+
+	}: {
+		assert!(Assets::<T>::can_mint(token, &who, amount));
+	}
+
+	// Benchmark `can_burn` under both branches, selected by `k`:
+	// * 0: The asset was never issued, so the check fails early
+	// * 1: The asset is issued, frozen in part and rate limited, and the check succeeds
+	can_burn {
+		let k in 0 .. 1;
+
+		let token = AssetId::Token(H160::zero());
+		let who: T::AccountId = account("holder", 0, 0);
+		let amount = U256::from(1000);
+		if k == 1 {
+			set_balance::<T>(&token, &who, &(amount * 2));
+			Frozen::<T>::insert(&token, &who, amount);
+			RateLimits::<T>::insert(&token, RateLimit {
+				window_blocks: 100u32.into(),
+				max_amount: amount,
+				spent: U256::zero(),
+				window_start: 0u32.into(),
+			});
+		}
+
+	}: {
+		assert_eq!(Assets::<T>::can_burn(token, &who, amount), k == 1);
+	}
+
+	// Benchmark `hold` under worst case conditions:
+	// * The account has no holds yet, so a new entry is created
+	hold {
+		let token = AssetId::Token(H160::zero());
+		let reason = hold_reason::<T>()?;
+		let who: T::AccountId = account("holder", 0, 0);
+		let amount = U256::from(1000);
+		set_balance::<T>(&token, &who, &amount);
+
+	}: {
+		Assets::<T>::hold(token, reason, &who, amount)?;
+	}
+	verify {
+		assert_eq!(Holds::<T>::get(&token, &who).len(), 1);
+	}
+
+	// Benchmark `release` under worst case conditions:
+	// * The last hold of the account is released, so its entry is removed
+	release {
+		let token = AssetId::Token(H160::zero());
+		let reason = hold_reason::<T>()?;
+		let who: T::AccountId = account("holder", 0, 0);
+		let amount = U256::from(1000);
+		set_balance::<T>(&token, &who, &amount);
+		Assets::<T>::hold(token, reason, &who, amount)?;
+
+	}: {
+		Assets::<T>::release(token, reason, &who, amount)?;
+	}
+	verify {
+		assert!(!Holds::<T>::contains_key(&token, &who));
+	}
+
+	// Benchmark `transfer_on_hold` under worst case conditions:
+	// * The last hold of the source is moved, so its entry is removed
+	// * The destination has no holds yet, so a new entry is created
+	transfer_on_hold {
+		let token = AssetId::Token(H160::zero());
+		let reason = hold_reason::<T>()?;
+		let source: T::AccountId = account("holder", 0, 0);
+		let dest: T::AccountId = account("recipient", 0, 0);
+		let amount = U256::from(1000);
+		set_balance::<T>(&token, &source, &amount);
+		Assets::<T>::hold(token, reason, &source, amount)?;
+
+	}: {
+		Assets::<T>::transfer_on_hold(token, reason, &source, &dest, amount)?;
+	}
+	verify {
+		assert!(!Holds::<T>::contains_key(&token, &source));
+		assert_eq!(Holds::<T>::get(&token, &dest).len(), 1);
+	}
+
+	// Benchmark `mint_and_lock` extrinsic under worst case conditions:
+	// * The origin is authorized, i.e. equals MintOrigin
+	// * The asset has a max supply, which is checked
+	// * The beneficiary has no holds yet, so a new entry is created
+	mint_and_lock {
+		let authorized_origin = match T::MintOrigin::successful_origin().into() {
+			Ok(raw) => raw,
+			Err(_) => return Err("Failed to get raw origin from origin"),
+		};
+		let token = AssetId::Token(H160::zero());
+		let reason = hold_reason::<T>()?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let beneficiary_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(beneficiary.clone());
+		let amount = U256::from(1000);
+		MaxSupply::insert(&token, U256::MAX);
+
+	}: _(authorized_origin, token, beneficiary_lookup, amount, reason)
+	verify {
+		assert_eq!(Assets::<T>::balance_on_hold(token, &beneficiary), amount);
+	}
+
+	// Benchmark `on_initialize` replaying queued deposits under worst case conditions:
+	// * The unpaused asset has `n` deposits queued, each to a different beneficiary
+	// * The asset has a max supply, which is checked on every deposit
+	replay_pending_deposits {
+		let n in 1 .. T::MaxPending::get();
+
+		let token = AssetId::Token(H160::zero());
+		let amount = U256::from(1000);
+		let mut pending = Vec::new();
+		for i in 0 .. n {
+			let beneficiary: T::AccountId = account("beneficiary", i, 0);
+			pending.push((beneficiary, amount));
+		}
+		let pending = BoundedVec::<_, T::MaxPending>::try_from(pending)
+			.map_err(|_| "Failed to bound the queued deposits")?;
+		PendingDeposits::<T>::insert(&token, pending);
+		MaxSupply::insert(&token, U256::MAX);
+
+	}: { Assets::<T>::replay_pending_deposits(); }
+	verify {
+		assert!(!PendingDeposits::<T>::contains_key(&token));
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		assert_eq!(get_balance::<T>(&token, &beneficiary), amount);
+	}
+
+	// Benchmark `merge_asset` extrinsic under worst case conditions:
+	// * Every merged account also holds a balance of the destination asset
+	// * The source asset is fully drained and removed
+	// * The destination asset is already held by `PREFILLED_ACCOUNTS` other accounts
+	merge_asset {
+		let n in 1 .. 1000;
+
+		let from = AssetId::Token(H160::repeat_byte(1));
+		let into = AssetId::Token(H160::repeat_byte(2));
+		for i in 0 .. n {
+			let who: T::AccountId = account("holder", i, 0);
+			Balances::<T>::insert(&from, &who, U256::one());
+			Balances::<T>::insert(&into, &who, U256::one());
+		}
+		TotalIssuance::insert(&from, U256::from(n));
+		TotalIssuance::insert(&into, U256::from(n));
+		prefill_accounts::<T>(&into, PREFILLED_ACCOUNTS);
+
+	}: _(RawOrigin::Root, from, into, n)
+	verify {
+		assert_eq!(TotalIssuance::get(&into), U256::from(n) * 2 + U256::from(PREFILLED_ACCOUNTS));
+		assert!(!TotalIssuance::contains_key(&from));
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -0,0 +1,103 @@
+//! Checks of the `MultiAsset` contract which hold for any implementation.
+//!
+//! Available to other crates with the `test-utils` feature.
+
+use frame_support::{assert_ok, storage::{with_transaction, TransactionOutcome}};
+use sp_core::U256;
+
+use snowbridge_core::{AssetId, MultiAsset};
+
+/// Depositing then withdrawing an amount restores the balance and total issuance.
+pub fn deposit_withdraw_round_trip<A, AccountId>(asset_id: AssetId, who: &AccountId, amount: U256)
+where
+	A: MultiAsset<AccountId>,
+{
+	let balance = A::balance(asset_id, who);
+	let total_issuance = A::total_issuance(asset_id);
+
+	assert_ok!(A::deposit(asset_id, who, amount));
+	assert_eq!(A::balance(asset_id, who), balance + amount);
+	assert_eq!(A::total_issuance(asset_id), total_issuance + amount);
+
+	assert_ok!(A::withdraw(asset_id, who, amount));
+	assert_eq!(A::balance(asset_id, who), balance);
+	assert_eq!(A::total_issuance(asset_id), total_issuance);
+}
+
+/// A transfer moves an amount between accounts without changing total issuance.
+pub fn transfer_preserves_total_issuance<A, AccountId>(
+	asset_id: AssetId,
+	from: &AccountId,
+	to: &AccountId,
+	amount: U256,
+)
+where
+	A: MultiAsset<AccountId>,
+{
+	assert_ok!(A::deposit(asset_id, from, amount));
+	let from_balance = A::balance(asset_id, from);
+	let to_balance = A::balance(asset_id, to);
+	let total_issuance = A::total_issuance(asset_id);
+
+	assert_ok!(A::transfer(asset_id, from, to, amount));
+	assert_eq!(A::balance(asset_id, from), from_balance - amount);
+	assert_eq!(A::balance(asset_id, to), to_balance + amount);
+	assert_eq!(A::total_issuance(asset_id), total_issuance);
+}
+
+/// Withdrawing more than the balance fails without changing any state.
+pub fn withdraw_beyond_balance_fails<A, AccountId>(asset_id: AssetId, who: &AccountId)
+where
+	A: MultiAsset<AccountId>,
+{
+	let balance = A::balance(asset_id, who);
+	assert!(A::withdraw(asset_id, who, balance + 1).is_err());
+	assert_eq!(A::balance(asset_id, who), balance);
+}
+
+/// Zero amounts and transfers to self are accepted and change nothing.
+pub fn zero_and_self_operations_are_noops<A, AccountId>(asset_id: AssetId, who: &AccountId)
+where
+	A: MultiAsset<AccountId>,
+{
+	let balance = A::balance(asset_id, who);
+	let total_issuance = A::total_issuance(asset_id);
+
+	assert_ok!(A::deposit(asset_id, who, U256::zero()));
+	assert_ok!(A::withdraw(asset_id, who, U256::zero()));
+	assert_ok!(A::transfer(asset_id, who, who, balance));
+	assert_eq!(A::balance(asset_id, who), balance);
+	assert_eq!(A::total_issuance(asset_id), total_issuance);
+}
+
+/// `can_deposit` predicts whether `deposit` succeeds. The deposit is rolled back afterwards.
+pub fn can_deposit_agrees_with_deposit<A, AccountId, F>(
+	can_deposit: F,
+	asset_id: AssetId,
+	who: &AccountId,
+	amount: U256,
+)
+where
+	A: MultiAsset<AccountId>,
+	F: Fn(AssetId, &AccountId, U256) -> bool,
+{
+	let expected = can_deposit(asset_id, who, amount);
+	let result = with_transaction(|| TransactionOutcome::Rollback(A::deposit(asset_id, who, amount)));
+	assert_eq!(result.is_ok(), expected);
+}
+
+/// `can_withdraw` predicts whether `withdraw` succeeds. The withdrawal is rolled back afterwards.
+pub fn can_withdraw_agrees_with_withdraw<A, AccountId, F>(
+	can_withdraw: F,
+	asset_id: AssetId,
+	who: &AccountId,
+	amount: U256,
+)
+where
+	A: MultiAsset<AccountId>,
+	F: Fn(AssetId, &AccountId, U256) -> bool,
+{
+	let expected = can_withdraw(asset_id, who, amount);
+	let result = with_transaction(|| TransactionOutcome::Rollback(A::withdraw(asset_id, who, amount)));
+	assert_eq!(result.is_ok(), expected);
+}
@@ -19,47 +19,272 @@
 //! - [`SingleAsset`](../snowbridge_core/assets/trait.SingleAsset.html): Functions for dealing with a
 //! single fungible asset.
 //!
+//! ### Holds
+//!
+//! Part of an account's balance can be placed on hold under a `Config::HoldReason`. Held funds
+//! are moved out of the free balance, still count towards the total issuance, and are tracked
+//! independently per reason. Held funds can be moved to another account with
+//! `transfer_on_hold`, arriving on hold under the same reason.
+//!
+//! ### Checkpoints
+//!
+//! When `Config::EnableCheckpoints` is set, every change to a free balance is recorded with the
+//! block in which it happened, and `balance_at` returns an account's balance as of a past block.
+//! At most `Config::MaxCheckpoints` are kept per account and asset.
+//!
+//! ### Pending Deposits
+//!
+//! A `MultiAsset::deposit` to a paused asset, such as a mint arriving over the bridge, is queued
+//! instead of failing, so that it is not lost with its message. At most `Config::MaxPending`
+//! deposits are queued per asset. Once the pause is lifted, they are credited in
+//! `on_initialize`. The pallet's own mint dispatchables still fail while paused.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
 //!
 //! - `transfer`: Transferring a balance between accounts.
+//! - `transfer_dry_run`: Running a transfer free of charge and rolling back its changes.
+//! - `transfer_fraction`: Transferring a fraction of the free balance to another account.
+//! - `transfer_to_ethereum`: Transferring a balance to the account derived from an Ethereum address.
+//! - `set_rate_limit`: Capping the amount of an asset that can leave accounts per window of blocks.
+//! - `set_freeze`: Freezing part of an account's balance.
+//! - `merge_asset`: Moving all balances of one asset into another, then removing the former.
+//! - `set_max_supply`: Capping the total issuance of an asset.
+//! - `transfer_and_burn`: Atomically transferring one asset from an account and burning another.
+//! - `clear_account`: Removing the storage entry of a zero balance.
+//! - `mint`: Issuing new balance of an asset to an account.
+//! - `mint_capped`: Issuing new balance, bounded by a per-call cap.
+//! - `mint_with_nonce`: Issuing new balance at most once per nonce.
+//! - `mint_batch`: Issuing new balance of an asset to many accounts in one call.
+//! - `mint_and_lock`: Issuing new balance of an asset and placing it on hold.
+//! - `transfer_multi`: Transferring balances of possibly different assets in one call.
+//! - `set_global_pause`: Halting all deposits, withdrawals and transfers of every asset.
+//! - `pause_asset`: Halting deposits, withdrawals and transfers of one asset, optionally until a block.
+//! - `unpause_asset`: Lifting the pause of one asset.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
+use frame_system::{self as system, ensure_root, ensure_signed};
 use frame_support::{
-	decl_error, decl_event, decl_module, decl_storage,
-	traits::Get,
-	dispatch::{DispatchResult, DispatchError},
-	weights::Weight,
+	decl_error, decl_event, decl_module, decl_storage, ensure,
+	traits::{Get, EnsureOrigin, OnRuntimeUpgrade},
+	dispatch::{DispatchResult, DispatchResultWithPostInfo, DispatchError, Parameter},
+	storage::{bounded_vec::BoundedVec, with_transaction, TransactionOutcome},
+	transactional,
+	weights::{Pays, Weight},
 };
 
-use sp_runtime::traits::StaticLookup;
-use sp_core::U256;
+use sp_runtime::traits::{Convert, Hash, Saturating, StaticLookup};
+use sp_core::{H160, U256, RuntimeDebug};
+use sp_std::convert::TryFrom;
+use codec::{Compact, Encode, Decode};
 
 use snowbridge_core::assets::{AssetId, MultiAsset, SingleAsset};
 use sp_std::marker;
 
 mod benchmarking;
 
+// Set while the `OnTransfer` hook runs, to reject transfers reentering from it. Unlike a storage
+// flag this is never written to the state, so guarding a transfer costs no database access.
+environmental::environmental!(in_transfer_hook: ());
+
 #[cfg(test)]
 mod mock;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod conformance;
+
 #[cfg(test)]
 mod tests;
 
 /// Weight functions needed for this pallet.
 pub trait WeightInfo {
 	fn transfer() -> Weight;
+	fn transfer_noop() -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn merge_asset(n: u32) -> Weight;
+	fn set_freeze() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn transfer_and_burn() -> Weight;
+	fn clear_account() -> Weight;
+	fn mint() -> Weight;
+	fn transfer_multi(n: u32) -> Weight;
+	fn set_global_pause() -> Weight;
+	fn mint_with_nonce() -> Weight;
+	fn pause_asset() -> Weight;
+	fn unpause_asset() -> Weight;
+	fn mint_batch(n: u32) -> Weight;
+	fn can_mint() -> Weight;
+	fn can_burn() -> Weight;
+	fn hold() -> Weight;
+	fn release() -> Weight;
+	fn transfer_on_hold() -> Weight;
+	fn mint_and_lock() -> Weight;
+	fn replay_pending_deposits(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
 	fn transfer() -> Weight { 0 }
+	fn transfer_noop() -> Weight { 0 }
+	fn set_rate_limit() -> Weight { 0 }
+	fn merge_asset(_: u32) -> Weight { 0 }
+	fn set_freeze() -> Weight { 0 }
+	fn set_max_supply() -> Weight { 0 }
+	fn transfer_and_burn() -> Weight { 0 }
+	fn clear_account() -> Weight { 0 }
+	fn mint() -> Weight { 0 }
+	fn transfer_multi(_: u32) -> Weight { 0 }
+	fn set_global_pause() -> Weight { 0 }
+	fn mint_with_nonce() -> Weight { 0 }
+	fn pause_asset() -> Weight { 0 }
+	fn unpause_asset() -> Weight { 0 }
+	fn mint_batch(_: u32) -> Weight { 0 }
+	fn can_mint() -> Weight { 0 }
+	fn can_burn() -> Weight { 0 }
+	fn hold() -> Weight { 0 }
+	fn release() -> Weight { 0 }
+	fn transfer_on_hold() -> Weight { 0 }
+	fn mint_and_lock() -> Weight { 0 }
+	fn replay_pending_deposits(_: u32) -> Weight { 0 }
+}
+
+/// Cap on the amount of an asset which may be transferred or withdrawn per window of blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RateLimit<BlockNumber> {
+	/// Length of a window in blocks.
+	pub window_blocks: BlockNumber,
+	/// Maximum amount which may be spent within a window.
+	pub max_amount: U256,
+	/// Amount spent within the current window.
+	pub spent: U256,
+	/// Block at which the current window started.
+	pub window_start: BlockNumber,
+}
+
+/// How the result of a division is rounded to a whole amount.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum RoundingMode {
+	/// Round towards zero.
+	Down,
+	/// Round away from zero.
+	Up,
+	/// Round to the nearest amount, with halves rounded away from zero.
+	HalfUp,
+}
+
+impl Default for RoundingMode {
+	fn default() -> Self {
+		RoundingMode::Down
+	}
+}
+
+/// Whether an operation must apply in full or may apply partially.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Precision {
+	/// Apply the exact amount or fail.
+	Exact,
+	/// Apply as much of the amount as possible.
+	BestEffort,
+}
+
+/// Whether an operation may reduce a balance below its frozen amount.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Fortitude {
+	/// Respect frozen amounts.
+	Polite,
+	/// Ignore frozen amounts.
+	Force,
+}
+
+/// Error returned by a [`TransferValidator`] to reject a transfer.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ValidationError;
+
+/// Screens transfers before they are applied, e.g. for sanctioned addresses.
+pub trait TransferValidator<AccountId> {
+	fn validate(asset_id: AssetId, from: &AccountId, to: &AccountId, amount: U256) -> Result<(), ValidationError>;
+}
+
+impl<AccountId> TransferValidator<AccountId> for () {
+	fn validate(_: AssetId, _: &AccountId, _: &AccountId, _: U256) -> Result<(), ValidationError> {
+		Ok(())
+	}
+}
+
+/// Notified of every transfer once its storage changes are complete.
+pub trait OnTransfer<AccountId> {
+	fn on_transfer(asset_id: AssetId, from: &AccountId, to: &AccountId, amount: U256);
+}
+
+impl<AccountId> OnTransfer<AccountId> for () {
+	fn on_transfer(_: AssetId, _: &AccountId, _: &AccountId, _: U256) {}
+}
+
+/// Notified whenever the total issuance of an asset changes, with its old and new values.
+pub trait OnSupplyChange {
+	fn on_supply_change(asset_id: AssetId, old: U256, new: U256);
+}
+
+impl OnSupplyChange for () {
+	fn on_supply_change(_: AssetId, _: U256, _: U256) {}
 }
 
 pub trait Config: system::Config {
 	type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+
+	/// Assets whose total issuance may never exceed a single unit. Balances of these assets
+	/// are indivisible and can only be transferred whole.
+	type UniqueAssets: Get<Vec<AssetId>>;
+
+	/// Derives the local account which receives transfers targeting an Ethereum address.
+	type EthereumAccountDerivation: Convert<H160, Self::AccountId>;
+
+	/// The origin which may update asset parameters such as rate limits.
+	type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Maps a basket group id to the assets sharing a common backing.
+	type BasketGroups: Convert<u32, Vec<AssetId>>;
+
+	/// Validator which may veto transfers before they are applied.
+	type TransferValidator: TransferValidator<Self::AccountId>;
+
+	/// The reason for which part of a balance is on hold.
+	type HoldReason: Parameter + Copy;
+
+	/// Maximum number of holds with distinct reasons per account and asset.
+	type MaxHolds: Get<u32>;
+
+	/// Whether free balances are checkpointed on every change, so that past balances can be
+	/// queried with `balance_at`.
+	type EnableCheckpoints: Get<bool>;
+
+	/// Maximum number of checkpoints kept per account and asset. The oldest are pruned first.
+	type MaxCheckpoints: Get<u32>;
+
+	/// Hook invoked after every transfer. Transfers, including transfers of held funds, made from
+	/// within the hook are rejected.
+	type OnTransfer: OnTransfer<Self::AccountId>;
+
+	/// The origin which may mint any asset with the `mint` dispatchable.
+	type MintOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Maximum number of items in a single `transfer_multi` or `mint_batch` call.
+	type MaxBatch: Get<u32>;
+
+	/// Maximum number of deposits queued per paused asset.
+	type MaxPending: Get<u32>;
+
+	/// Maximum amount a single `mint_capped` call may mint.
+	type MaxSingleMint: Get<U256>;
+
+	/// Hook invoked after every change to the total issuance of an asset.
+	type OnSupplyChange: OnSupplyChange;
+
+	/// The origin which may initiate transfers with `transfer`, `transfer_fraction`,
+	/// `transfer_multi` and `transfer_to_ethereum`, resolving to the account transferred from.
+	type TransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
 	/// Weight information for extrinsics in this pallet
 	type WeightInfo: WeightInfo;
 }
@@ -68,14 +293,47 @@ decl_storage! {
 	trait Store for Module<T: Config> as Assets {
 		pub TotalIssuance get(fn total_issuance): map hasher(blake2_128_concat) AssetId => U256;
 		pub Balances get(fn balances): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) T::AccountId => U256;
+		/// Balances on hold, by reason.
+		pub Holds get(fn holds): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) T::AccountId => BoundedVec<(T::HoldReason, U256), T::MaxHolds>;
+		/// Sum of all balances on hold for an asset, across accounts and reasons.
+		pub TotalHeld get(fn total_held): map hasher(blake2_128_concat) AssetId => U256;
+		/// Amount of the free balance of an account which cannot be spent.
+		pub Frozen get(fn frozen): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) T::AccountId => U256;
+		/// Number of times the frozen amount of an account has been set.
+		pub FreezeNonces get(fn freeze_nonce): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) T::AccountId => u32;
+		/// Outbound rate limits for assets which have one configured.
+		pub RateLimits get(fn rate_limits): map hasher(blake2_128_concat) AssetId => Option<RateLimit<T::BlockNumber>>;
+		/// Free balances of accounts as of the blocks in which they last changed, oldest first.
+		pub Checkpoints get(fn checkpoints): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) T::AccountId => BoundedVec<(T::BlockNumber, U256), T::MaxCheckpoints>;
+		/// Nonces of the mints already applied for each asset through `mint_with_nonce`.
+		pub ProcessedNonces get(fn processed_nonce): double_map hasher(blake2_128_concat) AssetId, hasher(blake2_128_concat) u64 => bool;
+		/// Whether all deposits, withdrawals and transfers are halted.
+		pub GlobalPaused get(fn global_paused): bool;
+		/// Caps on the total issuance of assets which have one configured.
+		pub MaxSupply get(fn max_supply): map hasher(blake2_128_concat) AssetId => Option<U256>;
+		/// Paused assets, with the block at which each pause expires, or `None` if it lasts
+		/// until lifted.
+		pub AssetPauses get(fn asset_pause): map hasher(blake2_128_concat) AssetId => Option<Option<T::BlockNumber>>;
+		/// Deposits which arrived while their asset was paused, oldest first. They are credited
+		/// in `on_initialize` once the pause is lifted.
+		pub PendingDeposits get(fn pending_deposits): map hasher(blake2_128_concat) AssetId => BoundedVec<(T::AccountId, U256), T::MaxPending>;
 	}
 	add_extra_genesis {
 		config(balances): Vec<(AssetId, T::AccountId, U256)>;
 		build(|config: &GenesisConfig<T>| {
 			for &(ref asset_id, ref who, amount) in config.balances.iter() {
-				let total_issuance = TotalIssuance::get(asset_id);
-				TotalIssuance::insert(asset_id, total_issuance + amount);
-				Balances::<T>::insert(asset_id, who, amount);
+				let total_issuance = TotalIssuance::get(asset_id).checked_add(amount)
+					.expect("genesis total issuance overflows");
+				assert!(
+					!Module::<T>::is_unique(*asset_id) || total_issuance <= U256::one(),
+					"genesis supply of a unique asset exceeds a single unit"
+				);
+				assert!(
+					Module::<T>::max_supply(asset_id).map_or(true, |max| total_issuance <= max),
+					"genesis supply exceeds the asset's maximum supply"
+				);
+				TotalIssuance::insert(asset_id, total_issuance);
+				Balances::<T>::mutate(asset_id, who, |balance| *balance = *balance + amount);
 			}
 		});
 	}
@@ -85,8 +343,48 @@ decl_event!(
 	pub enum Event<T>
 	where
 		<T as system::Config>::AccountId,
+		<T as system::Config>::BlockNumber,
+		HoldReason = <T as Config>::HoldReason,
 	{
 		Transferred(AssetId, AccountId, AccountId, U256),
+		/// A transfer whose amount fits in a `u128`, encoded compactly.
+		TransferredCompact(AssetId, AccountId, AccountId, Compact<u128>),
+		/// The rate limit of an asset was set to the given window and maximum amount, or removed.
+		RateLimitChanged(AssetId, Option<(BlockNumber, U256)>),
+		/// The balances of a number of accounts were moved from the first asset into the second.
+		BalancesMerged(AssetId, AssetId, u32),
+		/// All balances of an asset were merged away and its remaining state removed.
+		AssetDestroyed(AssetId),
+		/// The frozen amount of an account's free balance was set. The last field is the account's
+		/// new freeze nonce.
+		FreezeSet(AssetId, AccountId, U256, u32),
+		/// The cap on the total issuance of an asset was set or removed.
+		MaxSupplyChanged(AssetId, Option<U256>),
+		/// Some free balance of an account was placed on hold.
+		Held(AssetId, HoldReason, AccountId, U256),
+		/// Some balance on hold was released back to the free balance of an account.
+		Released(AssetId, HoldReason, AccountId, U256),
+		/// Some balance on hold was moved to another account, where it remains on hold.
+		HeldTransferred(AssetId, HoldReason, AccountId, AccountId, U256),
+		/// New balance of an asset was minted to an account.
+		Minted(AssetId, AccountId, U256),
+		/// Balance of an asset was burned from an account.
+		Burned(AssetId, AccountId, U256),
+		/// All deposits, withdrawals and transfers were halted or resumed.
+		GlobalPauseSet(bool),
+		/// Deposits, withdrawals and transfers of an asset were halted, until the given block if
+		/// any.
+		AssetPaused(AssetId, Option<BlockNumber>),
+		/// The pause of an asset was lifted.
+		AssetUnpaused(AssetId),
+		/// The zero balance of an account and its remaining state were removed.
+		AccountCleared(AssetId, AccountId),
+		/// A deposit to a paused asset was queued until the pause is lifted.
+		DepositQueued(AssetId, AccountId, U256),
+		/// A queued deposit was credited after its asset was unpaused.
+		DepositReplayed(AssetId, AccountId, U256),
+		/// A queued deposit could not be credited after its asset was unpaused, and was dropped.
+		DepositReplayFailed(AssetId, AccountId, U256),
 	}
 );
 
@@ -95,7 +393,51 @@ decl_error! {
 		TotalIssuanceOverflow,
 		TotalIssuanceUnderflow,
 		BalanceOverflow,
-		InsufficientBalance
+		InsufficientBalance,
+		/// The total issuance of a unique asset cannot exceed a single unit.
+		UniqueAssetSupplyExceeded,
+		/// The amount exceeds what may still be spent in the asset's current rate limit window.
+		RateLimited,
+		/// An asset cannot be merged into itself.
+		MergeIntoSelf,
+		/// The transfer was rejected by the configured transfer validator.
+		TransferRejected,
+		/// The fraction has a zero denominator or exceeds one.
+		InvalidFraction,
+		/// The account already has the maximum number of holds for the asset.
+		TooManyHolds,
+		/// The balance on hold for the reason is lower than the amount to release.
+		InsufficientHold,
+		/// The amount would dip into the frozen part of the free balance.
+		BalanceFrozen,
+		/// The frozen amount was changed since the expected freeze nonce was observed.
+		StaleFreezeNonce,
+		/// The total issuance would exceed the asset's maximum supply.
+		MaxSupplyExceeded,
+		/// The current total issuance is already above the new maximum supply.
+		SupplyExceedsNewCap,
+		/// A transfer or a transfer of held funds was attempted from within the `OnTransfer` hook.
+		ReentrantTransfer,
+		/// Only zero balances can be cleared.
+		BalanceNotZero,
+		/// All deposits, withdrawals and transfers are halted.
+		GloballyPaused,
+		/// Deposits, withdrawals and transfers of this asset are halted.
+		AssetPaused,
+		/// The amount exceeds the per-call cap, or the cap exceeds `MaxSingleMint`.
+		MintTooLarge,
+		/// A mint with this nonce was already applied for the asset.
+		DuplicateMint,
+		/// The amount must not be zero.
+		ZeroAmount,
+		/// A supply-conserving adjustment changed the total issuance.
+		SupplyNotConserved,
+		/// The asset still has balances on hold, which must be released first.
+		AssetHasHolds,
+		/// The account still has balances on hold, which must be released first.
+		AccountHasHolds,
+		/// The asset is paused and already has `MaxPending` deposits queued.
+		TooManyPendingDeposits,
 	}
 }
 
@@ -106,59 +448,1143 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			Self::replay_pending_deposits()
+		}
+
 		/// Transfer some free balance to another account.
+		///
+		/// Zero-amount transfers and transfers to self do no work and are refunded down to
+		/// `WeightInfo::transfer_noop`.
 		#[weight = T::WeightInfo::transfer()]
 		pub fn transfer(origin,
 						asset_id: AssetId,
 						dest: <T::Lookup as StaticLookup>::Source,
-						amount: U256) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+						amount: U256) -> DispatchResultWithPostInfo {
+			let who = T::TransferOrigin::ensure_origin(origin)?;
 			let dest = T::Lookup::lookup(dest)?;
-			<Self as MultiAsset<_>>::transfer(asset_id, &who, &dest, amount)
+			if amount.is_zero() || who == dest {
+				return Ok(Some(T::WeightInfo::transfer_noop()).into());
+			}
+			Self::do_transfer(asset_id, who, dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Call `transfer` inside a storage transaction which is always rolled back, and report its
+		/// result and the weight it used. Its storage changes and events are discarded, so nothing
+		/// but the outcome is observable. This is meant for integration testing and pays no fee.
+		#[weight = (T::WeightInfo::transfer(), Pays::No)]
+		pub fn transfer_dry_run(origin,
+						asset_id: AssetId,
+						dest: <T::Lookup as StaticLookup>::Source,
+						amount: U256) -> DispatchResultWithPostInfo {
+			with_transaction(|| TransactionOutcome::Rollback(Self::transfer(origin, asset_id, dest, amount)))
+		}
+
+		/// Transfer `numerator / denominator` of the free balance to another account, rounding down.
+		///
+		/// Fractions rounding to zero and transfers to self are refunded like in `transfer`.
+		#[weight = T::WeightInfo::transfer()]
+		pub fn transfer_fraction(origin,
+						asset_id: AssetId,
+						dest: <T::Lookup as StaticLookup>::Source,
+						numerator: u32,
+						denominator: u32) -> DispatchResultWithPostInfo {
+			let who = T::TransferOrigin::ensure_origin(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			let amount = Self::fraction_of(
+				Self::balances(asset_id, &who),
+				numerator,
+				denominator,
+				RoundingMode::default(),
+			)?;
+			if amount.is_zero() || who == dest {
+				return Ok(Some(T::WeightInfo::transfer_noop()).into());
+			}
+			Self::do_transfer(asset_id, who, dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Halt or resume all deposits, withdrawals and transfers of every asset. This is a kill
+		/// switch for incident response, independent of per-account freezes.
+		#[weight = T::WeightInfo::set_global_pause()]
+		pub fn set_global_pause(origin, paused: bool) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			<GlobalPaused>::put(paused);
+			Self::deposit_event(RawEvent::GlobalPauseSet(paused));
+			Ok(())
+		}
+
+		/// Halt deposits, withdrawals and transfers of an asset. With `until` set, the pause
+		/// lapses by itself once that block is reached.
+		#[weight = T::WeightInfo::pause_asset()]
+		pub fn pause_asset(origin, asset_id: AssetId, until: Option<T::BlockNumber>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			<AssetPauses<T>>::insert(asset_id, until);
+			Self::deposit_event(RawEvent::AssetPaused(asset_id, until));
+			Ok(())
+		}
+
+		/// Lift the pause of an asset, whether or not it has expired.
+		#[weight = T::WeightInfo::unpause_asset()]
+		pub fn unpause_asset(origin, asset_id: AssetId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			<AssetPauses<T>>::remove(asset_id);
+			Self::deposit_event(RawEvent::AssetUnpaused(asset_id));
+			Ok(())
+		}
+
+		/// Apply a batch of at most `MaxBatch` transfers, each of its own asset, destination and
+		/// amount. If any transfer fails, none of them are applied.
+		#[weight = T::WeightInfo::transfer_multi(transfers.len() as u32)]
+		#[transactional]
+		pub fn transfer_multi(origin,
+						transfers: BoundedVec<(AssetId, <T::Lookup as StaticLookup>::Source, U256), T::MaxBatch>) -> DispatchResult {
+			let who = T::TransferOrigin::ensure_origin(origin)?;
+			for (asset_id, dest, amount) in transfers.into_inner() {
+				let dest = T::Lookup::lookup(dest)?;
+				if amount.is_zero() || who == dest {
+					continue;
+				}
+				Self::do_transfer(asset_id, who.clone(), dest, amount)?;
+			}
+			Ok(())
+		}
+
+		/// Transfer some free balance to the account derived from an Ethereum address.
+		///
+		/// Zero-amount transfers and transfers to self are refunded like in `transfer`.
+		#[weight = T::WeightInfo::transfer()]
+		pub fn transfer_to_ethereum(origin,
+						asset_id: AssetId,
+						eth_dest: H160,
+						amount: U256) -> DispatchResultWithPostInfo {
+			let who = T::TransferOrigin::ensure_origin(origin)?;
+			let dest = T::EthereumAccountDerivation::convert(eth_dest);
+			if amount.is_zero() || who == dest {
+				return Ok(Some(T::WeightInfo::transfer_noop()).into());
+			}
+			Self::do_transfer(asset_id, who, dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Set or remove the outbound rate limit of an asset. A limit of `(window_blocks, max_amount)`
+		/// allows at most `max_amount` to be transferred or withdrawn every `window_blocks` blocks.
+		#[weight = T::WeightInfo::set_rate_limit()]
+		pub fn set_rate_limit(origin,
+						asset_id: AssetId,
+						limit: Option<(T::BlockNumber, U256)>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let rate_limit = limit.map(|(window_blocks, max_amount)| RateLimit {
+				window_blocks,
+				max_amount,
+				spent: U256::zero(),
+				window_start: <system::Pallet<T>>::block_number(),
+			});
+			<RateLimits<T>>::set(asset_id, rate_limit);
+			Self::deposit_event(RawEvent::RateLimitChanged(asset_id, limit));
+			Ok(())
+		}
+
+		/// Freeze `amount` of the free balance of `who`, so that only the excess can be spent.
+		/// A frozen amount of zero thaws the account.
+		///
+		/// `expected_nonce` must equal the account's current freeze nonce, so a change based on a
+		/// stale view of the freeze is rejected rather than applied out of order.
+		#[weight = T::WeightInfo::set_freeze()]
+		pub fn set_freeze(origin,
+						asset_id: AssetId,
+						who: <T::Lookup as StaticLookup>::Source,
+						amount: U256,
+						expected_nonce: u32) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Self::freeze_nonce(asset_id, &who) == expected_nonce, Error::<T>::StaleFreezeNonce);
+			let nonce = Self::bump_freeze_nonce(asset_id, &who);
+			if amount.is_zero() {
+				<Frozen<T>>::remove(asset_id, &who);
+			} else {
+				<Frozen<T>>::insert(asset_id, &who, amount);
+			}
+			Self::deposit_event(RawEvent::FreezeSet(asset_id, who, amount, nonce));
+			Ok(())
+		}
+
+		/// Move the balances of up to `max` accounts from `from_id` into `into_id`, along with any
+		/// frozen amounts. Neither asset may be paused, and balances of `from_id` on hold must be
+		/// released first. Once no balances of `from_id` remain, all of its remaining state is
+		/// removed, except for the nonces of processed mints so that they cannot be replayed.
+		///
+		/// The weight is refunded down to the number of accounts actually merged.
+		#[weight = T::WeightInfo::merge_asset(*max)]
+		#[transactional]
+		pub fn merge_asset(origin, from_id: AssetId, into_id: AssetId, max: u32) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(from_id != into_id, Error::<T>::MergeIntoSelf);
+			Self::ensure_not_paused(from_id)?;
+			Self::ensure_not_paused(into_id)?;
+			ensure!(Self::total_held(from_id).is_zero(), Error::<T>::AssetHasHolds);
+
+			let mut merged: u32 = 0;
+			let mut merged_amount = U256::zero();
+			for (who, amount) in <Balances<T>>::drain_prefix(from_id).take(max as usize) {
+				Self::do_deposit(into_id, &who, amount)?;
+				Self::record_checkpoint(from_id, &who);
+				let frozen = <Frozen<T>>::take(from_id, &who);
+				<FreezeNonces<T>>::remove(from_id, &who);
+				if !frozen.is_zero() {
+					let frozen = <Frozen<T>>::mutate(into_id, &who, |f| {
+						*f = f.saturating_add(frozen);
+						*f
+					});
+					let nonce = Self::bump_freeze_nonce(into_id, &who);
+					Self::deposit_event(RawEvent::FreezeSet(into_id, who.clone(), frozen, nonce));
+				}
+				merged_amount = merged_amount.checked_add(amount)
+					.ok_or(Error::<T>::TotalIssuanceOverflow)?;
+				merged += 1;
+			}
+
+			let old_total_issuance = Self::total_issuance(from_id);
+			let total_issuance = old_total_issuance.checked_sub(merged_amount)
+				.ok_or(Error::<T>::TotalIssuanceUnderflow)?;
+			<TotalIssuance>::insert(from_id, total_issuance);
+			if !merged_amount.is_zero() {
+				T::OnSupplyChange::on_supply_change(from_id, old_total_issuance, total_issuance);
+			}
+			Self::deposit_event(RawEvent::BalancesMerged(from_id, into_id, merged));
+
+			if <Balances<T>>::iter_prefix(from_id).next().is_none() {
+				<TotalIssuance>::remove(from_id);
+				<RateLimits<T>>::remove(from_id);
+				<MaxSupply>::remove(from_id);
+				<TotalHeld>::remove(from_id);
+				<AssetPauses<T>>::remove(from_id);
+				// Accounts without a balance may still have a freeze or checkpoints left
+				<Frozen<T>>::remove_prefix(from_id, None);
+				<FreezeNonces<T>>::remove_prefix(from_id, None);
+				<Checkpoints<T>>::remove_prefix(from_id, None);
+				Self::deposit_event(RawEvent::AssetDestroyed(from_id));
+			}
+			Ok(Some(T::WeightInfo::merge_asset(merged)).into())
+		}
+
+		/// Set or remove the cap on the total issuance of an asset. The cap cannot be set below
+		/// the current total issuance.
+		#[weight = T::WeightInfo::set_max_supply()]
+		pub fn set_max_supply(origin, asset_id: AssetId, max: Option<U256>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(max) = max {
+				ensure!(Self::total_issuance(asset_id) <= max, Error::<T>::SupplyExceedsNewCap);
+			}
+			<MaxSupply>::set(asset_id, max);
+			Self::deposit_event(RawEvent::MaxSupplyChanged(asset_id, max));
+			Ok(())
+		}
+
+		/// Transfer `transfer.2` of asset `transfer.0` from `who` to `transfer.1`, and burn
+		/// `burn.1` of asset `burn.0` from `who`. If either fails, neither is applied.
+		#[weight = T::WeightInfo::transfer_and_burn()]
+		#[transactional]
+		pub fn transfer_and_burn(origin,
+						who: <T::Lookup as StaticLookup>::Source,
+						transfer: (AssetId, <T::Lookup as StaticLookup>::Source, U256),
+						burn: (AssetId, U256)) -> DispatchResult {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let (asset_id, dest, amount) = transfer;
+			let dest = T::Lookup::lookup(dest)?;
+			let (burn_id, burn_amount) = burn;
+
+			Self::do_transfer(asset_id, who.clone(), dest, amount)?;
+			<Self as MultiAsset<_>>::withdraw(burn_id, &who, burn_amount)
+		}
+
+		/// Remove the storage entries of a zero balance of `who`, which are otherwise kept once the
+		/// account was credited, along with its freeze and checkpoints. Accounts with balances on
+		/// hold cannot be cleared. Callable by `who` itself or by `UpdateOrigin`.
+		#[weight = T::WeightInfo::clear_account()]
+		pub fn clear_account(origin, asset_id: AssetId, who: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+			let who = T::Lookup::lookup(who)?;
+			if let Err(origin) = T::UpdateOrigin::try_origin(origin) {
+				let signer = ensure_signed(origin)?;
+				ensure!(signer == who, DispatchError::BadOrigin);
+			}
+			ensure!(Self::balances(asset_id, &who).is_zero(), Error::<T>::BalanceNotZero);
+			ensure!(!<Holds<T>>::contains_key(asset_id, &who), Error::<T>::AccountHasHolds);
+			<Balances<T>>::remove(asset_id, &who);
+			<Frozen<T>>::remove(asset_id, &who);
+			<FreezeNonces<T>>::remove(asset_id, &who);
+			<Checkpoints<T>>::remove(asset_id, &who);
+			Self::deposit_event(RawEvent::AccountCleared(asset_id, who));
+			Ok(())
+		}
+
+		/// Mint `amount` of an asset to `beneficiary`, increasing its total issuance.
+		#[weight = T::WeightInfo::mint()]
+		pub fn mint(origin,
+						asset_id: AssetId,
+						beneficiary: <T::Lookup as StaticLookup>::Source,
+						amount: U256) -> DispatchResult {
+			T::MintOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_deposit(asset_id, &beneficiary, amount)?;
+			Self::deposit_minted_event(asset_id, beneficiary, amount);
+			Ok(())
+		}
+
+		/// Mint like `mint`, but at most once for each `nonce` of the asset, so that replaying
+		/// the same bridge message cannot mint twice. Zero amounts are rejected, so that no nonce
+		/// is used up by a mint which changes nothing.
+		#[weight = T::WeightInfo::mint_with_nonce()]
+		pub fn mint_with_nonce(origin,
+						asset_id: AssetId,
+						beneficiary: <T::Lookup as StaticLookup>::Source,
+						amount: U256,
+						nonce: u64) -> DispatchResult {
+			T::MintOrigin::ensure_origin(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(!<ProcessedNonces>::contains_key(asset_id, nonce), Error::<T>::DuplicateMint);
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_deposit(asset_id, &beneficiary, amount)?;
+			<ProcessedNonces>::insert(asset_id, nonce, true);
+			Self::deposit_minted_event(asset_id, beneficiary, amount);
+			Ok(())
+		}
+
+		/// Mint like `mint`, but only if `amount` is at most `max_this_call`, which in turn may be
+		/// at most `MaxSingleMint`. This bounds the damage a compromised mint origin can do in
+		/// a single call.
+		#[weight = T::WeightInfo::mint()]
+		pub fn mint_capped(origin,
+						asset_id: AssetId,
+						beneficiary: <T::Lookup as StaticLookup>::Source,
+						amount: U256,
+						max_this_call: U256) -> DispatchResult {
+			T::MintOrigin::ensure_origin(origin)?;
+			ensure!(
+				amount <= max_this_call && max_this_call <= T::MaxSingleMint::get(),
+				Error::<T>::MintTooLarge
+			);
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_deposit(asset_id, &beneficiary, amount)?;
+			Self::deposit_minted_event(asset_id, beneficiary, amount);
+			Ok(())
+		}
+
+		/// Mint an asset to each of a batch of at most `MaxBatch` beneficiaries, skipping zero
+		/// amounts. If any mint fails, for example by exceeding the max supply, none of them are
+		/// applied.
+		#[weight = T::WeightInfo::mint_batch(recipients.len() as u32)]
+		#[transactional]
+		pub fn mint_batch(origin,
+						asset_id: AssetId,
+						recipients: BoundedVec<(<T::Lookup as StaticLookup>::Source, U256), T::MaxBatch>) -> DispatchResultWithPostInfo {
+			T::MintOrigin::ensure_origin(origin)?;
+			let mut minted: u32 = 0;
+			for (beneficiary, amount) in recipients.into_inner() {
+				let beneficiary = T::Lookup::lookup(beneficiary)?;
+				if amount.is_zero() {
+					continue;
+				}
+				Self::do_deposit(asset_id, &beneficiary, amount)?;
+				Self::deposit_minted_event(asset_id, beneficiary, amount);
+				minted += 1;
+			}
+			Ok(Some(T::WeightInfo::mint_batch(minted)).into())
+		}
+
+		/// Mint `amount` of an asset to `beneficiary` and place it on hold under `reason` in the
+		/// same call, so that the minted funds are never spendable before they are released.
+		#[weight = T::WeightInfo::mint_and_lock()]
+		pub fn mint_and_lock(origin,
+						asset_id: AssetId,
+						beneficiary: <T::Lookup as StaticLookup>::Source,
+						amount: U256,
+						reason: T::HoldReason) -> DispatchResult {
+			T::MintOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::deposit_and_hold(asset_id, reason, &beneficiary, amount)
 		}
 	}
 }
 
-impl<T: Config> MultiAsset<T::AccountId> for Module<T> {
+impl<T: Config> Module<T> {
+	/// Whether `asset_id` is configured as a unique asset.
+	pub fn is_unique(asset_id: AssetId) -> bool {
+		T::UniqueAssets::get().contains(&asset_id)
+	}
 
-	fn total_issuance(asset_id: AssetId) -> U256 {
-		Module::<T>::total_issuance(asset_id)
+	/// Whether depositing `amount` to `who` would currently be credited. This only reads storage
+	/// and mirrors the checks of `deposit`. Deposits to a paused asset are queued instead, and
+	/// report `false`.
+	pub fn can_mint(asset_id: AssetId, who: &T::AccountId, amount: U256) -> bool {
+		if amount.is_zero() {
+			return true
+		}
+		if Self::ensure_not_paused(asset_id).is_err() {
+			return false
+		}
+		let new_total_issuance = match Self::total_issuance(asset_id).checked_add(amount) {
+			Some(total_issuance) => total_issuance,
+			None => return false,
+		};
+		(!Self::is_unique(asset_id) || new_total_issuance <= U256::one())
+			&& Self::max_supply(asset_id).map_or(true, |max| new_total_issuance <= max)
+			&& Self::balances(asset_id, who).checked_add(amount).is_some()
 	}
 
-	fn balance(asset_id: AssetId, who: &T::AccountId) -> U256 {
-		Module::<T>::balances(asset_id, who)
+	/// Whether withdrawing `amount` from `who` would currently succeed. This only reads storage
+	/// and mirrors the checks of `withdraw`.
+	pub fn can_burn(asset_id: AssetId, who: &T::AccountId, amount: U256) -> bool {
+		if amount.is_zero() {
+			return true
+		}
+		if Self::ensure_not_paused(asset_id).is_err() {
+			return false
+		}
+		let remaining = match Self::balances(asset_id, who).checked_sub(amount) {
+			Some(remaining) => remaining,
+			None => return false,
+		};
+		Self::total_issuance(asset_id) >= amount
+			&& remaining >= Self::frozen(asset_id, who)
+			&& Self::rate_limit_allows(asset_id, amount)
 	}
 
-	fn deposit(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult  {
+	/// Assert in debug builds that an account balance never exceeds the total issuance of its
+	/// asset. This compiles to nothing in release builds.
+	fn ensure_supply_consistent(balance: U256, total_issuance: U256) {
+		debug_assert!(balance <= total_issuance, "account balance exceeds total issuance");
+	}
+
+	/// The total balance of `who` on hold for `asset_id`, across all reasons.
+	pub fn balance_on_hold(asset_id: AssetId, who: &T::AccountId) -> U256 {
+		Self::holds(asset_id, who)
+			.iter()
+			.fold(U256::zero(), |total, (_, amount)| total.saturating_add(*amount))
+	}
+
+	/// The balance of `who` on hold for `asset_id` under `reason`.
+	pub fn balance_on_hold_for(asset_id: AssetId, reason: &T::HoldReason, who: &T::AccountId) -> U256 {
+		Self::holds(asset_id, who)
+			.iter()
+			.find(|(r, _)| r == reason)
+			.map(|(_, amount)| *amount)
+			.unwrap_or_default()
+	}
+
+	/// Move `amount` of the free balance of `who` on hold under `reason`.
+	pub fn hold(asset_id: AssetId, reason: T::HoldReason, who: &T::AccountId, amount: U256) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(())
 		}
-		<Balances<T>>::try_mutate(asset_id, who, |balance| -> Result<(), DispatchError> {
-			let current_total_issuance = Self::total_issuance(asset_id);
-			let new_total_issuance = current_total_issuance.checked_add(amount)
-				.ok_or(Error::<T>::TotalIssuanceOverflow)?;
-			*balance = balance.checked_add(amount)
-				.ok_or(Error::<T>::BalanceOverflow)?;
-			<TotalIssuance>::insert(asset_id, new_total_issuance);
+		Self::ensure_not_paused(asset_id)?;
+		let mut holds = Self::holds(asset_id, who).to_vec();
+		match holds.iter_mut().find(|(r, _)| *r == reason) {
+			Some((_, held)) => {
+				*held = held.checked_add(amount).ok_or(Error::<T>::BalanceOverflow)?;
+			},
+			None => holds.push((reason, amount)),
+		}
+		let holds = BoundedVec::try_from(holds).map_err(|_| Error::<T>::TooManyHolds)?;
+
+		<Balances<T>>::try_mutate(asset_id, who, |balance| -> DispatchResult {
+			*balance = balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+			Self::ensure_not_frozen(asset_id, who, *balance)?;
 			Ok(())
+		})?;
+		Self::record_checkpoint(asset_id, who);
+		// Held balances are part of the total issuance, so their sum cannot overflow
+		<TotalHeld>::mutate(asset_id, |total| *total = total.saturating_add(amount));
+		<Holds<T>>::insert(asset_id, who, holds);
+		Self::deposit_event(RawEvent::Held(asset_id, reason, who.clone(), amount));
+		Ok(())
+	}
+
+	/// Mint `amount` to `who` and immediately place it on hold under `reason`, so that the
+	/// minted funds are never spendable before release. Emits `Minted` followed by `Held`.
+	/// Either both steps apply or neither.
+	#[transactional]
+	pub fn deposit_and_hold(asset_id: AssetId, reason: T::HoldReason, who: &T::AccountId, amount: U256) -> DispatchResult {
+		Self::do_deposit(asset_id, who, amount)?;
+		Self::deposit_minted_event(asset_id, who.clone(), amount);
+		Self::hold(asset_id, reason, who, amount)
+	}
+
+	/// Burn `amount` from `burn_from` and mint the same amount to `mint_to`, leaving the total
+	/// issuance unchanged. The bridge uses this to move supply between accounts while keeping it
+	/// invariant. The burn comes first, so an asset at its max supply can still be adjusted.
+	/// Either both steps apply or neither.
+	///
+	/// Emits `Burned` followed by `Minted`. Since the total issuance does not change,
+	/// `OnSupplyChange` is not notified.
+	#[transactional]
+	pub fn net_zero_adjust(
+		asset_id: AssetId,
+		mint_to: &T::AccountId,
+		burn_from: &T::AccountId,
+		amount: U256,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let total_issuance = Self::total_issuance(asset_id);
+		Self::decrease_issuance(asset_id, burn_from, amount, Fortitude::Polite)?;
+		Self::increase_issuance(asset_id, mint_to, amount)?;
+		ensure!(Self::total_issuance(asset_id) == total_issuance, Error::<T>::SupplyNotConserved);
+		Self::deposit_burned_event(asset_id, burn_from.clone(), amount);
+		Self::deposit_minted_event(asset_id, mint_to.clone(), amount);
+		Ok(())
+	}
+
+	/// Exchange the free balances of `a` and `b`. Either may be zero, in which case the other
+	/// account ends up holding the whole balance. Holds, freezes and the total issuance are
+	/// unaffected.
+	///
+	/// Each nonzero side of the swap is treated as a transfer: it is screened by the transfer
+	/// validator, counts against the rate limit, is reported to `OnTransfer` and emits a transfer
+	/// event. Each account's new balance must still cover its frozen amount.
+	#[transactional]
+	pub fn swap_balances(asset_id: AssetId, a: &T::AccountId, b: &T::AccountId) -> DispatchResult {
+		if a == b {
+			return Ok(())
+		}
+		Self::ensure_not_paused(asset_id)?;
+		Self::ensure_not_in_transfer_hook()?;
+		let balance_a = Self::balances(asset_id, a);
+		let balance_b = Self::balances(asset_id, b);
+		let sides = [(a, b, balance_a), (b, a, balance_b)];
+		for (from, to, amount) in sides.iter() {
+			if amount.is_zero() {
+				continue;
+			}
+			T::TransferValidator::validate(asset_id, from, to, *amount)
+				.map_err(|_| Error::<T>::TransferRejected)?;
+			Self::consume_rate_limit(asset_id, *amount)?;
+		}
+		Self::ensure_not_frozen(asset_id, a, balance_b)?;
+		Self::ensure_not_frozen(asset_id, b, balance_a)?;
+		<Balances<T>>::swap(asset_id, a, asset_id, b);
+		Self::record_checkpoint(asset_id, a);
+		Self::record_checkpoint(asset_id, b);
+
+		// All storage changes are complete, so the hook only ever observes a consistent state
+		Self::with_transfer_hook_guard(|| {
+			for (from, to, amount) in sides.iter().filter(|(_, _, amount)| !amount.is_zero()) {
+				T::OnTransfer::on_transfer(asset_id, from, to, *amount);
+			}
+		});
+		for (from, to, amount) in sides.iter().filter(|(_, _, amount)| !amount.is_zero()) {
+			Self::deposit_transferred_event(asset_id, (*from).clone(), (*to).clone(), *amount);
+		}
+		Ok(())
+	}
+
+	/// The total issuance of an asset less the balances on hold, i.e. the supply which can
+	/// currently be spent or withdrawn (frozen amounts notwithstanding).
+	pub fn reducible_total_issuance(asset_id: AssetId) -> U256 {
+		Self::total_issuance(asset_id).saturating_sub(Self::total_held(asset_id))
+	}
+
+	/// How much more of an asset may be minted before reaching its max supply, or `None` if the
+	/// asset is uncapped.
+	pub fn remaining_mintable(asset_id: AssetId) -> Option<U256> {
+		Self::max_supply(asset_id).map(|max| {
+			max.checked_sub(Self::total_issuance(asset_id)).unwrap_or_else(U256::zero)
 		})
 	}
 
-	fn withdraw(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult  {
+	/// Release `amount` held under `reason` back to the free balance of `who`. Holds under other
+	/// reasons are unaffected.
+	pub fn release(asset_id: AssetId, reason: T::HoldReason, who: &T::AccountId, amount: U256) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Self::ensure_not_paused(asset_id)?;
+		let mut holds = Self::holds(asset_id, who).to_vec();
+		let index = holds.iter().position(|(r, _)| *r == reason)
+			.ok_or(Error::<T>::InsufficientHold)?;
+		let remaining = holds[index].1.checked_sub(amount)
+			.ok_or(Error::<T>::InsufficientHold)?;
+		if remaining.is_zero() {
+			holds.remove(index);
+		} else {
+			holds[index].1 = remaining;
+		}
+		let holds = BoundedVec::<_, T::MaxHolds>::try_from(holds)
+			.map_err(|_| Error::<T>::TooManyHolds)?;
+
+		<Balances<T>>::try_mutate(asset_id, who, |balance| -> DispatchResult {
+			*balance = balance.checked_add(amount).ok_or(Error::<T>::BalanceOverflow)?;
+			Ok(())
+		})?;
+		Self::record_checkpoint(asset_id, who);
+		<TotalHeld>::mutate(asset_id, |total| *total = total.saturating_sub(amount));
+		if holds.is_empty() {
+			<Holds<T>>::remove(asset_id, who);
+		} else {
+			<Holds<T>>::insert(asset_id, who, holds);
+		}
+		Self::deposit_event(RawEvent::Released(asset_id, reason, who.clone(), amount));
+		Ok(())
+	}
+
+	/// Move `amount` held under `reason` from `source` to `dest`, where it stays on hold under the
+	/// same reason. Free balances and the total issuance are unaffected. Returns the amount moved.
+	pub fn transfer_on_hold(
+		asset_id: AssetId,
+		reason: T::HoldReason,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: U256,
+	) -> Result<U256, DispatchError> {
+		if amount.is_zero() || source == dest {
+			return Ok(U256::zero())
+		}
+		Self::ensure_not_paused(asset_id)?;
+		Self::ensure_not_in_transfer_hook()?;
+		T::TransferValidator::validate(asset_id, source, dest, amount)
+			.map_err(|_| Error::<T>::TransferRejected)?;
+
+		let mut source_holds = Self::holds(asset_id, source).to_vec();
+		let index = source_holds.iter().position(|(r, _)| *r == reason)
+			.ok_or(Error::<T>::InsufficientHold)?;
+		let remaining = source_holds[index].1.checked_sub(amount)
+			.ok_or(Error::<T>::InsufficientHold)?;
+		if remaining.is_zero() {
+			source_holds.remove(index);
+		} else {
+			source_holds[index].1 = remaining;
+		}
+		let source_holds = BoundedVec::<_, T::MaxHolds>::try_from(source_holds)
+			.map_err(|_| Error::<T>::TooManyHolds)?;
+
+		let mut dest_holds = Self::holds(asset_id, dest).to_vec();
+		match dest_holds.iter_mut().find(|(r, _)| *r == reason) {
+			Some((_, held)) => {
+				*held = held.checked_add(amount).ok_or(Error::<T>::BalanceOverflow)?;
+			},
+			None => dest_holds.push((reason, amount)),
+		}
+		let dest_holds = BoundedVec::<_, T::MaxHolds>::try_from(dest_holds)
+			.map_err(|_| Error::<T>::TooManyHolds)?;
+
+		if source_holds.is_empty() {
+			<Holds<T>>::remove(asset_id, source);
+		} else {
+			<Holds<T>>::insert(asset_id, source, source_holds);
+		}
+		<Holds<T>>::insert(asset_id, dest, dest_holds);
+		Self::deposit_event(RawEvent::HeldTransferred(asset_id, reason, source.clone(), dest.clone(), amount));
+		Ok(amount)
+	}
+
+	/// Record the current free balance of `who` as of this block, if checkpoints are enabled.
+	/// Once `MaxCheckpoints` are kept, the oldest checkpoint is pruned to make room.
+	fn record_checkpoint(asset_id: AssetId, who: &T::AccountId) {
+		if !T::EnableCheckpoints::get() || T::MaxCheckpoints::get() == 0 {
+			return;
+		}
+		let now = <system::Pallet<T>>::block_number();
+		let balance = Self::balances(asset_id, who);
+		let mut checkpoints = Self::checkpoints(asset_id, who).to_vec();
+		match checkpoints.last_mut() {
+			Some((block, last)) if *block == now => *last = balance,
+			_ => {
+				if checkpoints.len() >= T::MaxCheckpoints::get() as usize {
+					checkpoints.remove(0);
+				}
+				checkpoints.push((now, balance));
+			},
+		}
+		if let Ok(checkpoints) = BoundedVec::try_from(checkpoints) {
+			<Checkpoints<T>>::insert(asset_id, who, checkpoints);
+		}
+	}
+
+	/// The free balance of `who` as of the end of `block`, reconstructed from checkpoints.
+	///
+	/// Returns `None` if `block` predates the retained history, i.e. older checkpoints may have
+	/// been pruned. Accounts which never had a checkpoint report a zero balance, so this is only
+	/// meaningful for blocks after checkpoints were enabled.
+	pub fn balance_at(asset_id: AssetId, who: &T::AccountId, block: T::BlockNumber) -> Option<U256> {
+		let checkpoints = Self::checkpoints(asset_id, who);
+		match checkpoints.iter().rev().find(|(b, _)| *b <= block) {
+			Some((_, balance)) => Some(*balance),
+			None if checkpoints.len() >= T::MaxCheckpoints::get() as usize && !checkpoints.is_empty() => None,
+			None => Some(U256::zero()),
+		}
+	}
+
+	/// Withdraw `amount` from the free balance of `who`. Unless `force` is `Fortitude::Force`,
+	/// the withdrawal may not dip into the frozen amount.
+	fn do_withdraw(asset_id: AssetId, who: &T::AccountId, amount: U256, force: Fortitude) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(())
 		}
-		<Balances<T>>::try_mutate(asset_id, who, |balance| -> Result<(), DispatchError> {
+		let (old, new) = Self::decrease_issuance(asset_id, who, amount, force)?;
+		T::OnSupplyChange::on_supply_change(asset_id, old, new);
+		Ok(())
+	}
+
+	/// Withdraw like `do_withdraw`, but without notifying `OnSupplyChange`. Returns the total
+	/// issuance before and after the withdrawal.
+	fn decrease_issuance(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		amount: U256,
+		force: Fortitude,
+	) -> Result<(U256, U256), DispatchError> {
+		Self::ensure_not_paused(asset_id)?;
+		let issuance = <Balances<T>>::try_mutate(asset_id, who, |balance| -> Result<(U256, U256), DispatchError> {
 			let current_total_issuance = Self::total_issuance(asset_id);
 			let new_total_issuance = current_total_issuance.checked_sub(amount)
 				.ok_or(Error::<T>::TotalIssuanceUnderflow)?;
 			*balance = balance.checked_sub(amount)
 				.ok_or(Error::<T>::InsufficientBalance)?;
+			if force == Fortitude::Polite {
+				Self::ensure_not_frozen(asset_id, who, *balance)?;
+			}
+			Self::ensure_supply_consistent(*balance, new_total_issuance);
+			Self::consume_rate_limit(asset_id, amount)?;
+			<TotalIssuance>::insert(asset_id, new_total_issuance);
+			Ok((current_total_issuance, new_total_issuance))
+		})?;
+		Self::record_checkpoint(asset_id, who);
+		Ok(issuance)
+	}
+
+	/// Credit `amount` to `who`, failing if the asset is paused. Pallet mints use this directly,
+	/// so that only deposits made through `MultiAsset` are queued while paused.
+	fn do_deposit(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let (old, new) = Self::increase_issuance(asset_id, who, amount)?;
+		T::OnSupplyChange::on_supply_change(asset_id, old, new);
+		Ok(())
+	}
+
+	/// Queue a deposit to a paused asset, to be credited once the pause is lifted.
+	fn queue_deposit(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult {
+		let mut pending = Self::pending_deposits(asset_id).to_vec();
+		pending.push((who.clone(), amount));
+		let pending = BoundedVec::<_, T::MaxPending>::try_from(pending)
+			.map_err(|_| Error::<T>::TooManyPendingDeposits)?;
+		<PendingDeposits<T>>::insert(asset_id, pending);
+		Self::deposit_event(RawEvent::DepositQueued(asset_id, who.clone(), amount));
+		Ok(())
+	}
+
+	/// Credit the queued deposits of every asset which is no longer paused, in the order they
+	/// arrived. A deposit which fails, for example by exceeding the max supply, is dropped.
+	fn replay_pending_deposits() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		if Self::global_paused() {
+			return weight
+		}
+		let mut inspected: u64 = 0;
+		let ready: Vec<_> = <PendingDeposits<T>>::iter()
+			.filter(|(asset_id, _)| {
+				inspected += 1;
+				!Self::is_asset_paused(*asset_id)
+			})
+			.collect();
+		weight = weight.saturating_add(T::DbWeight::get().reads(inspected.saturating_mul(2)));
+
+		let mut replayed: u32 = 0;
+		for (asset_id, pending) in ready {
+			<PendingDeposits<T>>::remove(asset_id);
+			for (who, amount) in pending.into_inner() {
+				match Self::do_deposit(asset_id, &who, amount) {
+					Ok(()) => Self::deposit_event(RawEvent::DepositReplayed(asset_id, who, amount)),
+					Err(_) => Self::deposit_event(RawEvent::DepositReplayFailed(asset_id, who, amount)),
+				}
+				replayed += 1;
+			}
+		}
+		weight.saturating_add(T::WeightInfo::replay_pending_deposits(replayed))
+	}
+
+	/// Deposit like `do_deposit`, but without notifying `OnSupplyChange`. Returns the total
+	/// issuance before and after the deposit.
+	fn increase_issuance(asset_id: AssetId, who: &T::AccountId, amount: U256) -> Result<(U256, U256), DispatchError> {
+		Self::ensure_not_paused(asset_id)?;
+		let issuance = <Balances<T>>::try_mutate(asset_id, who, |balance| -> Result<(U256, U256), DispatchError> {
+			let current_total_issuance = Self::total_issuance(asset_id);
+			let new_total_issuance = current_total_issuance.checked_add(amount)
+				.ok_or(Error::<T>::TotalIssuanceOverflow)?;
+			ensure!(
+				!Self::is_unique(asset_id) || new_total_issuance <= U256::one(),
+				Error::<T>::UniqueAssetSupplyExceeded
+			);
+			ensure!(
+				Self::max_supply(asset_id).map_or(true, |max| new_total_issuance <= max),
+				Error::<T>::MaxSupplyExceeded
+			);
+			*balance = balance.checked_add(amount)
+				.ok_or(Error::<T>::BalanceOverflow)?;
+			Self::ensure_supply_consistent(*balance, new_total_issuance);
 			<TotalIssuance>::insert(asset_id, new_total_issuance);
+			Ok((current_total_issuance, new_total_issuance))
+		})?;
+		Self::record_checkpoint(asset_id, who);
+		Ok(issuance)
+	}
+
+	/// Burn up to `amount` from the free balance of `who`, returning the amount burned.
+	///
+	/// With `Precision::Exact` exactly `amount` is burned or the call fails, while with
+	/// `Precision::BestEffort` as much as possible up to `amount` is burned. Frozen funds are
+	/// only burned with `Fortitude::Force`. Funds on hold are never burned and must be released
+	/// first.
+	pub fn burn_from(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		amount: U256,
+		precision: Precision,
+		force: Fortitude,
+	) -> Result<U256, DispatchError> {
+		let amount = match precision {
+			Precision::Exact => amount,
+			Precision::BestEffort => {
+				let balance = Self::balances(asset_id, who);
+				let reducible = match force {
+					Fortitude::Polite => balance.saturating_sub(Self::frozen(asset_id, who)),
+					Fortitude::Force => balance,
+				};
+				amount.min(reducible)
+			},
+		};
+		Self::do_withdraw(asset_id, who, amount, force)?;
+		Ok(amount)
+	}
+
+	/// Whether any of the free balance of `who` is unspendable: part of it is frozen, the asset
+	/// is paused, or all assets are globally paused.
+	pub fn is_frozen(asset_id: AssetId, who: &T::AccountId) -> bool {
+		Self::global_paused() || Self::is_asset_paused(asset_id) || !Self::frozen(asset_id, who).is_zero()
+	}
+
+	/// Whether an asset is paused. A pause with an expiry block no longer applies from that
+	/// block on, even though it stays in storage until lifted.
+	pub fn is_asset_paused(asset_id: AssetId) -> bool {
+		match Self::asset_pause(asset_id) {
+			None => false,
+			Some(None) => true,
+			Some(Some(until)) => <system::Pallet<T>>::block_number() < until,
+		}
+	}
+
+	fn ensure_not_paused(asset_id: AssetId) -> DispatchResult {
+		ensure!(!Self::global_paused(), Error::<T>::GloballyPaused);
+		ensure!(!Self::is_asset_paused(asset_id), Error::<T>::AssetPaused);
+		Ok(())
+	}
+
+	/// Advance the freeze nonce of `who`, returning the new nonce. Every change to a frozen
+	/// amount must bump it, so that `set_freeze` calls based on the old amount are rejected.
+	fn bump_freeze_nonce(asset_id: AssetId, who: &T::AccountId) -> u32 {
+		<FreezeNonces<T>>::mutate(asset_id, who, |nonce| {
+			*nonce = nonce.wrapping_add(1);
+			*nonce
+		})
+	}
+
+	fn ensure_not_in_transfer_hook() -> DispatchResult {
+		ensure!(in_transfer_hook::with(|_| ()).is_none(), Error::<T>::ReentrantTransfer);
+		Ok(())
+	}
+
+	/// Run `f`, which notifies `OnTransfer`, such that transfers attempted from within it are
+	/// rejected by `ensure_not_in_transfer_hook`.
+	fn with_transfer_hook_guard(f: impl FnOnce()) {
+		in_transfer_hook::using(&mut (), f)
+	}
+
+	/// Ensure the free balance remaining after a debit still covers the frozen amount.
+	fn ensure_not_frozen(asset_id: AssetId, who: &T::AccountId, remaining: U256) -> DispatchResult {
+		ensure!(remaining >= Self::frozen(asset_id, who), Error::<T>::BalanceFrozen);
+		Ok(())
+	}
+
+	/// Record `amount` as spent against the rate limit of `asset_id`, if it has one. A new window
+	/// starts lazily once the current one has elapsed.
+	fn consume_rate_limit(asset_id: AssetId, amount: U256) -> DispatchResult {
+		<RateLimits<T>>::try_mutate(asset_id, |maybe_limit| -> DispatchResult {
+			if let Some(limit) = maybe_limit {
+				if Self::window_elapsed(limit) {
+					limit.window_start = <system::Pallet<T>>::block_number();
+					limit.spent = U256::zero();
+				}
+				let spent = limit.spent.checked_add(amount)
+					.ok_or(Error::<T>::RateLimited)?;
+				ensure!(spent <= limit.max_amount, Error::<T>::RateLimited);
+				limit.spent = spent;
+			}
 			Ok(())
 		})
 	}
 
+	/// Whether `amount` could currently be spent against the rate limit of `asset_id`, without
+	/// consuming it.
+	fn rate_limit_allows(asset_id: AssetId, amount: U256) -> bool {
+		match Self::rate_limits(asset_id) {
+			None => true,
+			Some(limit) => {
+				let spent = if Self::window_elapsed(&limit) { U256::zero() } else { limit.spent };
+				spent.checked_add(amount).map_or(false, |spent| spent <= limit.max_amount)
+			},
+		}
+	}
+
+	fn window_elapsed(limit: &RateLimit<T::BlockNumber>) -> bool {
+		<system::Pallet<T>>::block_number() >= limit.window_start.saturating_add(limit.window_blocks)
+	}
+
+	/// Transfer and emit a transfer event. Zero-amount transfers and transfers to self change
+	/// nothing and emit no event.
+	fn do_transfer(asset_id: AssetId, from: T::AccountId, to: T::AccountId, amount: U256) -> DispatchResult {
+		if amount.is_zero() || from == to {
+			return Ok(())
+		}
+		<Self as MultiAsset<_>>::transfer(asset_id, &from, &to, amount)?;
+		Self::deposit_transferred_event(asset_id, from, to, amount);
+		Ok(())
+	}
+
+	/// The weight charged for a transfer, for callers that transfer on behalf of their own
+	/// dispatchables.
+	pub fn transfer_weight() -> Weight {
+		T::WeightInfo::transfer()
+	}
+
+	/// The weight of a `can_mint` check, for callers that check a deposit before making it.
+	pub fn can_mint_weight() -> Weight {
+		T::WeightInfo::can_mint()
+	}
+
+	/// The weight of a `can_burn` check, for callers that check a withdrawal before making it.
+	pub fn can_burn_weight() -> Weight {
+		T::WeightInfo::can_burn()
+	}
+
+	/// The weight of `hold`, for callers that place holds on behalf of their own dispatchables.
+	pub fn hold_weight() -> Weight {
+		T::WeightInfo::hold()
+	}
+
+	/// The weight of `release`, for callers that release holds on behalf of their own
+	/// dispatchables.
+	pub fn release_weight() -> Weight {
+		T::WeightInfo::release()
+	}
+
+	/// The weight of `transfer_on_hold`, for callers that move held funds on behalf of their own
+	/// dispatchables.
+	pub fn transfer_on_hold_weight() -> Weight {
+		T::WeightInfo::transfer_on_hold()
+	}
+
+	/// Transfer and emit an event like the `transfer` dispatchable, also returning the weight
+	/// consumed. Zero-amount transfers and transfers to self only cost `WeightInfo::transfer_noop`.
+	pub fn do_transfer_weighed(
+		asset_id: AssetId,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: U256,
+	) -> (DispatchResult, Weight) {
+		if amount.is_zero() || from == to {
+			return (Ok(()), T::WeightInfo::transfer_noop());
+		}
+		(Self::do_transfer(asset_id, from, to, amount), Self::transfer_weight())
+	}
+
+	/// `amount * numerator / denominator`, rounded according to `rounding`. The result never
+	/// exceeds `amount`, since the fraction is at most one.
+	fn fraction_of(
+		amount: U256,
+		numerator: u32,
+		denominator: u32,
+		rounding: RoundingMode,
+	) -> Result<U256, DispatchError> {
+		ensure!(denominator != 0 && numerator <= denominator, Error::<T>::InvalidFraction);
+		let scaled = amount.checked_mul(numerator.into())
+			.ok_or(Error::<T>::BalanceOverflow)?;
+		let denominator = U256::from(denominator);
+		let (quotient, remainder) = scaled.div_mod(denominator);
+		let round_up = match rounding {
+			RoundingMode::Down => false,
+			RoundingMode::Up => !remainder.is_zero(),
+			RoundingMode::HalfUp => remainder >= denominator - remainder,
+		};
+		if round_up {
+			Ok(quotient + U256::one())
+		} else {
+			Ok(quotient)
+		}
+	}
+
+	/// The `n` accounts with the largest free balances of `asset_id`, largest first. This
+	/// iterates every balance of the asset while keeping at most `n` candidates, and is only
+	/// meant for off-chain queries.
+	pub fn largest_holders(asset_id: AssetId, n: u32) -> Vec<(T::AccountId, U256)> {
+		let n = n as usize;
+		if n == 0 {
+			return Vec::new();
+		}
+		let mut top: Vec<(T::AccountId, U256)> = Vec::with_capacity(n + 1);
+		for (who, balance) in <Balances<T>>::iter_prefix(asset_id) {
+			if balance.is_zero() {
+				continue;
+			}
+			let index = top.iter().position(|(_, b)| *b < balance).unwrap_or(top.len());
+			if index < n {
+				top.insert(index, (who, balance));
+				top.truncate(n);
+			}
+		}
+		top
+	}
+
+	/// The summed total issuance of all assets in a basket group. On overflow the sum saturates
+	/// at `U256::MAX` and the returned flag is set.
+	pub fn group_total_supply(group: u32) -> (U256, bool) {
+		T::BasketGroups::convert(group)
+			.iter()
+			.fold((U256::zero(), false), |(total, overflowed), asset_id| {
+				match total.checked_add(Self::total_issuance(*asset_id)) {
+					Some(total) => (total, overflowed),
+					None => (U256::MAX, true),
+				}
+			})
+	}
+
+	/// Every asset with a recorded total issuance, paired with that issuance. This iterates the
+	/// whole `TotalIssuance` map and is only meant for off-chain snapshots and audits.
+	pub fn export_assets() -> Vec<(AssetId, U256)> {
+		TotalIssuance::iter().collect()
+	}
+
+	/// A page of at most `count` entries of [`Module::export_assets`], skipping the first
+	/// `start`. Entries are in storage order, which is stable while the map is unchanged.
+	pub fn export_assets_paged(start: u32, count: u32) -> Vec<(AssetId, U256)> {
+		TotalIssuance::iter()
+			.skip(start as usize)
+			.take(count as usize)
+			.collect()
+	}
+
+	/// Check that the total issuance of every asset equals the sum of its free and held
+	/// balances, and that no balance exists for an asset without a recorded total issuance.
+	/// This iterates all asset state and is only meant for try-runtime checks and tests.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (asset_id, total_issuance) in TotalIssuance::iter() {
+			let free = <Balances<T>>::iter_prefix_values(asset_id)
+				.try_fold(U256::zero(), |total, balance| total.checked_add(balance))
+				.ok_or("sum of free balances overflows")?;
+			let held = <Holds<T>>::iter_prefix_values(asset_id)
+				.flat_map(|holds| holds.to_vec())
+				.try_fold(U256::zero(), |total, (_, amount)| total.checked_add(amount))
+				.ok_or("sum of held balances overflows")?;
+			let sum = free.checked_add(held).ok_or("sum of balances overflows")?;
+			if sum != total_issuance {
+				return Err("total issuance does not match the sum of balances");
+			}
+			if held != TotalHeld::get(asset_id) {
+				return Err("total held does not match the sum of holds");
+			}
+		}
+		for (asset_id, _, balance) in <Balances<T>>::iter() {
+			if !balance.is_zero() && !TotalIssuance::contains_key(asset_id) {
+				return Err("balance exists for an asset without total issuance");
+			}
+		}
+		Ok(())
+	}
+
+	/// The event topic under which transfers of `asset_id` are indexed, so that clients can
+	/// subscribe to the events of a single asset.
+	pub fn asset_topic(asset_id: AssetId) -> T::Hash {
+		T::Hashing::hash_of(&asset_id)
+	}
+
+	/// The event topic under which transfers from or to `who` are indexed, so that clients can
+	/// subscribe to the activity of a single account.
+	pub fn account_topic(who: &T::AccountId) -> T::Hash {
+		T::Hashing::hash_of(who)
+	}
+
+	/// Emit a transfer event indexed by the asset's topic and the topics of both accounts,
+	/// preferring the compact variant when `amount` fits in a `u128`.
+	fn deposit_transferred_event(asset_id: AssetId, from: T::AccountId, to: T::AccountId, amount: U256) {
+		let topics = [Self::asset_topic(asset_id), Self::account_topic(&from), Self::account_topic(&to)];
+		let event = match u128::try_from(amount) {
+			Ok(amount) => RawEvent::TransferredCompact(asset_id, from, to, Compact(amount)),
+			Err(_) => RawEvent::Transferred(asset_id, from, to, amount),
+		};
+		<system::Pallet<T>>::deposit_event_indexed(
+			&topics,
+			<T as Config>::Event::from(event).into(),
+		);
+	}
+
+	/// Emit a mint event indexed by the asset's topic and the topic of the beneficiary. Like
+	/// transfers, zero-amount mints change nothing and emit no event.
+	fn deposit_minted_event(asset_id: AssetId, beneficiary: T::AccountId, amount: U256) {
+		if amount.is_zero() {
+			return
+		}
+		let topics = [Self::asset_topic(asset_id), Self::account_topic(&beneficiary)];
+		<system::Pallet<T>>::deposit_event_indexed(
+			&topics,
+			<T as Config>::Event::from(RawEvent::Minted(asset_id, beneficiary, amount)).into(),
+		);
+	}
+
+	/// Emit a burn event indexed by the asset's topic and the topic of the account burned from.
+	fn deposit_burned_event(asset_id: AssetId, who: T::AccountId, amount: U256) {
+		let topics = [Self::asset_topic(asset_id), Self::account_topic(&who)];
+		<system::Pallet<T>>::deposit_event_indexed(
+			&topics,
+			<T as Config>::Event::from(RawEvent::Burned(asset_id, who, amount)).into(),
+		);
+	}
+}
+
+impl<T: Config> MultiAsset<T::AccountId> for Module<T> {
+
+	fn total_issuance(asset_id: AssetId) -> U256 {
+		Module::<T>::total_issuance(asset_id)
+	}
+
+	fn balance(asset_id: AssetId, who: &T::AccountId) -> U256 {
+		Module::<T>::balances(asset_id, who)
+	}
+
+	/// Account freezes, asset pauses and the global pause all count, see `Module::is_frozen`.
+	fn is_frozen(asset_id: AssetId, who: &T::AccountId) -> bool {
+		Module::<T>::is_frozen(asset_id, who)
+	}
+
+	/// Deposits to a paused asset are queued rather than rejected, and credited once the pause
+	/// is lifted. See `PendingDeposits`.
+	fn deposit(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult  {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		if Self::ensure_not_paused(asset_id).is_err() {
+			return Self::queue_deposit(asset_id, who, amount)
+		}
+		Self::do_deposit(asset_id, who, amount)
+	}
+
+	fn withdraw(asset_id: AssetId, who: &T::AccountId, amount: U256) -> DispatchResult  {
+		Self::do_withdraw(asset_id, who, amount, Fortitude::Polite)
+	}
+
 	fn transfer(
 		asset_id: AssetId,
 		from: &T::AccountId,
@@ -168,13 +1594,25 @@ impl<T: Config> MultiAsset<T::AccountId> for Module<T> {
 		if amount.is_zero() || from == to {
 			return Ok(())
 		}
+		Self::ensure_not_paused(asset_id)?;
+		Self::ensure_not_in_transfer_hook()?;
+		T::TransferValidator::validate(asset_id, from, to, amount)
+			.map_err(|_| Error::<T>::TransferRejected)?;
 		<Balances<T>>::try_mutate(asset_id, from, |from_balance| -> DispatchResult {
 			<Balances<T>>::try_mutate(asset_id, to, |to_balance| -> DispatchResult {
 				*from_balance = from_balance.checked_sub(amount).ok_or(Error::<T>::InsufficientBalance)?;
+				Self::ensure_not_frozen(asset_id, from, *from_balance)?;
 				*to_balance = to_balance.checked_add(amount).ok_or(Error::<T>::BalanceOverflow)?;
+				Self::consume_rate_limit(asset_id, amount)?;
 				Ok(())
 			})
-		})
+		})?;
+		Self::record_checkpoint(asset_id, from);
+		Self::record_checkpoint(asset_id, to);
+
+		// All storage changes are complete, so the hook only ever observes a consistent state
+		Self::with_transfer_hook_guard(|| T::OnTransfer::on_transfer(asset_id, from, to, amount));
+		Ok(())
 	}
 }
 
@@ -217,3 +1655,26 @@ where
 		<Module<T> as MultiAsset<_>>::transfer(I::get(), source, dest, amount)
 	}
 }
+
+/// Runs the `do_try_state` checks after a runtime upgrade when built with `try-runtime`.
+/// Add it to the runtime's `Executive` migrations.
+pub struct CheckTryState<T>(marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CheckTryState<T> {
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		Module::<T>::do_try_state()
+	}
+}
+
+/// Derives an account id by hashing an Ethereum address.
+pub struct HashedEthereumAccount<AccountId>(marker::PhantomData<AccountId>);
+
+impl<AccountId> Convert<H160, AccountId> for HashedEthereumAccount<AccountId>
+where
+	AccountId: From<[u8; 32]>,
+{
+	fn convert(address: H160) -> AccountId {
+		sp_io::hashing::blake2_256(&(b"ethereum", address).encode()).into()
+	}
+}
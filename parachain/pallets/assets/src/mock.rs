@@ -1,12 +1,14 @@
 // Mock runtime
 use super::*;
 
-use sp_core::H256;
+use sp_core::{H160, H256};
 use frame_support::parameter_types;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, IdentifyAccount, Verify}, testing::Header, MultiSignature
 };
 use sp_std::convert::From;
+use std::cell::RefCell;
+use sp_keyring::AccountKeyring as Keyring;
 
 use crate as assets;
 
@@ -31,6 +33,23 @@ parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 }
 
+parameter_types! {
+	pub UniqueAssets: Vec<AssetId> = vec![AssetId::Token(H160::repeat_byte(0xff))];
+	pub const MaxHolds: u32 = 2;
+	pub const EnableCheckpoints: bool = true;
+	pub const MaxCheckpoints: u32 = 3;
+	pub const MaxBatch: u32 = 3;
+	pub const MaxPending: u32 = 2;
+	pub MaxSingleMint: U256 = U256::from(1000);
+}
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum HoldReason {
+	Custody,
+	Governance,
+	Staking,
+}
+
 impl system::Config for Test {
 	type BaseCallFilter = ();
 	type BlockWeights = ();
@@ -57,9 +76,146 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+pub struct BasketGroups;
+
+impl Convert<u32, Vec<AssetId>> for BasketGroups {
+	fn convert(group: u32) -> Vec<AssetId> {
+		match group {
+			0 => vec![AssetId::ETH, AssetId::Token(H160::repeat_byte(1))],
+			1 => vec![AssetId::Token(H160::repeat_byte(2)), AssetId::Token(H160::repeat_byte(3))],
+			_ => vec![],
+		}
+	}
+}
+
+/// Rejects any transfer from or to Ferdie.
+pub struct BlockFerdie;
+
+impl TransferValidator<AccountId> for BlockFerdie {
+	fn validate(_: AssetId, from: &AccountId, to: &AccountId, _: U256) -> Result<(), ValidationError> {
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		if *from == ferdie || *to == ferdie {
+			return Err(ValidationError);
+		}
+		Ok(())
+	}
+}
+
+thread_local! {
+	/// Balances of sender and recipient observed by `RecordTransfers`, and whether a transfer
+	/// and a transfer of held funds attempted from within the hook succeeded.
+	pub static OBSERVED_TRANSFERS: RefCell<Vec<(U256, U256, bool, bool)>> = RefCell::new(Vec::new());
+}
+
+/// Records the balances visible to the hook and attempts to reenter `transfer` and
+/// `transfer_on_hold`.
+pub struct RecordTransfers;
+
+impl OnTransfer<AccountId> for RecordTransfers {
+	fn on_transfer(asset_id: AssetId, from: &AccountId, to: &AccountId, amount: U256) {
+		let reentered = <Assets as MultiAsset<_>>::transfer(asset_id, to, from, amount).is_ok();
+		let reentered_on_hold = Assets::transfer_on_hold(asset_id, HoldReason::Custody, to, from, U256::one()).is_ok();
+		OBSERVED_TRANSFERS.with(|observed| observed.borrow_mut().push((
+			Assets::balances(asset_id, from),
+			Assets::balances(asset_id, to),
+			reentered,
+			reentered_on_hold,
+		)));
+	}
+}
+
+thread_local! {
+	/// Supply changes observed by `RecordSupplyChanges`, as (asset, old, new).
+	pub static SUPPLY_CHANGES: RefCell<Vec<(AssetId, U256, U256)>> = RefCell::new(Vec::new());
+}
+
+/// Records every change to the total issuance of an asset.
+pub struct RecordSupplyChanges;
+
+impl OnSupplyChange for RecordSupplyChanges {
+	fn on_supply_change(asset_id: AssetId, old: U256, new: U256) {
+		SUPPLY_CHANGES.with(|changes| changes.borrow_mut().push((asset_id, old, new)));
+	}
+}
+
+thread_local! {
+	/// Accounts allowed to initiate transfers through `EnsureWhitelisted`, or `None` to allow
+	/// any signed origin.
+	pub static TRANSFER_WHITELIST: RefCell<Option<Vec<AccountId>>> = RefCell::new(None);
+}
+
+fn is_whitelisted(who: &AccountId) -> bool {
+	TRANSFER_WHITELIST.with(|whitelist| {
+		whitelist.borrow().as_ref().map_or(true, |whitelist| whitelist.contains(who))
+	})
+}
+
+/// Ensures a signed origin whose account is in `TRANSFER_WHITELIST`.
+pub struct EnsureWhitelisted;
+
+impl EnsureOrigin<Origin> for EnsureWhitelisted {
+	type Success = AccountId;
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if is_whitelisted(&who) => Ok(who),
+			r => Err(Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::signed(Keyring::Alice.into())
+	}
+}
+
+/// Distinct nonzero weights, so that tests can tell which weight a call was charged.
+pub struct MockWeightInfo;
+
+impl WeightInfo for MockWeightInfo {
+	fn transfer() -> Weight { 1_000 }
+	fn transfer_noop() -> Weight { 100 }
+	fn set_rate_limit() -> Weight { 201 }
+	fn merge_asset(n: u32) -> Weight { 5_000 + 2_000 * n as Weight }
+	fn set_freeze() -> Weight { 202 }
+	fn set_max_supply() -> Weight { 203 }
+	fn transfer_and_burn() -> Weight { 1_500 }
+	fn clear_account() -> Weight { 204 }
+	fn mint() -> Weight { 800 }
+	fn transfer_multi(n: u32) -> Weight { 300 + 1_000 * n as Weight }
+	fn set_global_pause() -> Weight { 205 }
+	fn mint_with_nonce() -> Weight { 900 }
+	fn pause_asset() -> Weight { 206 }
+	fn unpause_asset() -> Weight { 207 }
+	fn mint_batch(n: u32) -> Weight { 400 + 800 * n as Weight }
+	fn can_mint() -> Weight { 50 }
+	fn can_burn() -> Weight { 60 }
+	fn hold() -> Weight { 700 }
+	fn release() -> Weight { 650 }
+	fn transfer_on_hold() -> Weight { 600 }
+	fn mint_and_lock() -> Weight { 1_400 }
+	fn replay_pending_deposits(n: u32) -> Weight { 208 + 600 * n as Weight }
+}
+
 impl assets::Config for Test {
 	type Event = Event;
-	type WeightInfo = ();
+	type UniqueAssets = UniqueAssets;
+	type EthereumAccountDerivation = assets::HashedEthereumAccount<AccountId>;
+	type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type BasketGroups = BasketGroups;
+	type TransferValidator = BlockFerdie;
+	type HoldReason = HoldReason;
+	type MaxHolds = MaxHolds;
+	type EnableCheckpoints = EnableCheckpoints;
+	type MaxCheckpoints = MaxCheckpoints;
+	type OnTransfer = RecordTransfers;
+	type MintOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxBatch = MaxBatch;
+	type MaxSingleMint = MaxSingleMint;
+	type OnSupplyChange = RecordSupplyChanges;
+	type TransferOrigin = EnsureWhitelisted;
+	type MaxPending = MaxPending;
+	type WeightInfo = MockWeightInfo;
 }
 
 pub fn new_tester() -> sp_io::TestExternalities {
@@ -1,11 +1,21 @@
-use crate::mock::{new_tester, AccountId, Assets, Test};
-use frame_support::{assert_ok, assert_noop};
+use crate::conformance;
+use crate::mock::{new_tester, AccountId, Assets, Event, HoldReason, MaxBatch, MockWeightInfo, Origin, System, Test, OBSERVED_TRANSFERS, SUPPLY_CHANGES, TRANSFER_WHITELIST};
+use frame_support::{assert_ok, assert_noop, dispatch::DispatchError, traits::OnInitialize, weights::GetDispatchInfo};
 use sp_keyring::AccountKeyring as Keyring;
-use crate::{Balances, TotalIssuance};
+use crate::{Balances, Checkpoints, FreezeNonces, Frozen, Holds, PendingDeposits, RateLimits, TotalHeld, TotalIssuance};
 use snowbridge_core::{AssetId, MultiAsset};
+use sp_core::H160;
 
 use super::*;
 
+fn last_event() -> Event {
+	System::events().pop().expect("Event expected").event
+}
+
+fn batch<T>(items: Vec<T>) -> BoundedVec<T, MaxBatch> {
+	BoundedVec::try_from(items).expect("batch exceeds MaxBatch")
+}
+
 fn set_balance<T>(asset_id: AssetId, account_id: &AccountId, amount: T)
 	where T : Into<U256> + Copy
 {
@@ -134,3 +144,2119 @@ fn transfer_should_raise_insufficient_balance() {
 		);
 	});
 }
+
+#[test]
+fn unique_asset_supply_cannot_exceed_one_unit() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::Token(H160::repeat_byte(0xff));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		assert_noop!(
+			<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 2.into()),
+			Error::<Test>::UniqueAssetSupplyExceeded
+		);
+
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 1.into()),
+			Error::<Test>::UniqueAssetSupplyExceeded
+		);
+		assert_eq!(TotalIssuance::get(&asset_id), 1.into());
+	});
+}
+
+#[test]
+fn unique_asset_can_only_be_transferred_whole() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::Token(H160::repeat_byte(0xff));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 1.into()),
+			Error::<Test>::UniqueAssetSupplyExceeded
+		);
+
+		// A single unit cannot be split, so any transfer other than the whole unit fails
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 2.into()),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 1.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 0.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 1.into());
+		assert_eq!(TotalIssuance::get(&asset_id), 1.into());
+	});
+}
+
+#[test]
+fn genesis_accepts_single_unit_of_unique_asset() {
+	let asset_id = AssetId::Token(H160::repeat_byte(0xff));
+	let alice: AccountId = Keyring::Alice.into();
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	GenesisConfig::<Test> { balances: vec![(asset_id, alice.clone(), 1.into())] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 1.into());
+		assert_eq!(TotalIssuance::get(&asset_id), 1.into());
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis supply of a unique asset exceeds a single unit")]
+fn genesis_rejects_unique_asset_supply_above_one_unit() {
+	let asset_id = AssetId::Token(H160::repeat_byte(0xff));
+	let alice: AccountId = Keyring::Alice.into();
+	let bob: AccountId = Keyring::Bob.into();
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let _ = GenesisConfig::<Test> {
+		balances: vec![(asset_id, alice, 1.into()), (asset_id, bob, 1.into())],
+	}.assimilate_storage(&mut storage);
+}
+
+#[test]
+fn minimum_balance_defaults_to_zero() {
+	new_tester().execute_with(|| {
+		assert_eq!(<Assets as MultiAsset<_>>::minimum_balance(AssetId::ETH), U256::zero());
+		assert_eq!(
+			<Assets as MultiAsset<_>>::minimum_balance(AssetId::Token(H160::repeat_byte(1))),
+			U256::zero()
+		);
+	});
+}
+
+#[test]
+fn transfer_of_small_amount_emits_compact_event() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 250.into()));
+
+		assert_eq!(
+			Event::Assets(RawEvent::TransferredCompact(asset_id, alice, bob, Compact(250))),
+			last_event()
+		);
+	});
+}
+
+#[test]
+fn transfer_of_large_amount_emits_full_event() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let amount = U256::from(u128::MAX) + 1;
+		set_balance(asset_id, &alice, U256::MAX);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), amount));
+
+		assert_eq!(
+			Event::Assets(RawEvent::Transferred(asset_id, alice, bob, amount)),
+			last_event()
+		);
+	});
+}
+
+#[test]
+fn transfer_to_ethereum_credits_derived_account() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let eth_dest = H160::repeat_byte(7);
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::transfer_to_ethereum(Origin::signed(alice.clone()), asset_id, eth_dest, 200.into()));
+
+		let dest: AccountId = HashedEthereumAccount::<AccountId>::convert(eth_dest);
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 300.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &dest), 200.into());
+	});
+}
+
+#[test]
+fn ethereum_account_derivation_is_deterministic() {
+	let first: AccountId = HashedEthereumAccount::<AccountId>::convert(H160::repeat_byte(7));
+	let second: AccountId = HashedEthereumAccount::<AccountId>::convert(H160::repeat_byte(7));
+	let other: AccountId = HashedEthereumAccount::<AccountId>::convert(H160::repeat_byte(8));
+	assert_eq!(first, second);
+	assert_ne!(first, other);
+}
+
+#[test]
+fn withdrawal_exceeding_total_issuance_should_not_clamp_total_issuance() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		Balances::<Test>::insert(&asset_id, &alice, U256::from(500));
+		TotalIssuance::insert(&asset_id, U256::from(100));
+
+		assert_noop!(
+			<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 200.into()),
+			Error::<Test>::TotalIssuanceUnderflow
+		);
+		assert_eq!(TotalIssuance::get(&asset_id), 100.into());
+	});
+}
+
+#[test]
+fn set_rate_limit_requires_update_origin() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			Assets::set_rate_limit(Origin::signed(alice), AssetId::ETH, Some((10, 100.into()))),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn rate_limit_caps_outbound_amount_per_window() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::set_rate_limit(Origin::root(), asset_id, Some((10, 100.into()))));
+
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 60.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 40.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 1.into()),
+			Error::<Test>::RateLimited
+		);
+		assert_noop!(
+			<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 1.into()),
+			Error::<Test>::RateLimited
+		);
+
+		System::set_block_number(11);
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 100.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 300.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 160.into());
+	});
+}
+
+#[test]
+fn rate_limit_is_not_consumed_by_failed_transfers() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 50);
+
+		assert_ok!(Assets::set_rate_limit(Origin::root(), asset_id, Some((10, 100.into()))));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 80.into()),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(RateLimits::<Test>::get(asset_id).unwrap().spent, U256::zero());
+	});
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "account balance exceeds total issuance")]
+fn deposit_should_assert_supply_consistency_in_debug_builds() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		Balances::<Test>::insert(&asset_id, &alice, U256::from(500));
+		TotalIssuance::insert(&asset_id, U256::from(100));
+
+		let _ = <Assets as MultiAsset<_>>::deposit(asset_id, &alice, 10.into());
+	});
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "account balance exceeds total issuance")]
+fn withdrawal_should_assert_supply_consistency_in_debug_builds() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		Balances::<Test>::insert(&asset_id, &alice, U256::from(500));
+		TotalIssuance::insert(&asset_id, U256::from(100));
+
+		let _ = <Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 10.into());
+	});
+}
+
+#[test]
+fn merge_asset_moves_balances_incrementally() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &bob, 50.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(into_id, &alice, 10.into()));
+
+		assert_ok!(Assets::merge_asset(Origin::root(), from_id, into_id, 1));
+		assert_eq!(TotalIssuance::get(&into_id) + TotalIssuance::get(&from_id), 160.into());
+		assert!(TotalIssuance::contains_key(&from_id));
+
+		assert_ok!(Assets::merge_asset(Origin::root(), from_id, into_id, 1));
+		assert_eq!(Balances::<Test>::get(&into_id, &alice), 110.into());
+		assert_eq!(Balances::<Test>::get(&into_id, &bob), 50.into());
+		assert_eq!(TotalIssuance::get(&into_id), 160.into());
+		assert!(!TotalIssuance::contains_key(&from_id));
+		assert!(!Balances::<Test>::contains_key(&from_id, &alice));
+		assert!(!Balances::<Test>::contains_key(&from_id, &bob));
+		assert_eq!(Event::Assets(RawEvent::AssetDestroyed(from_id)), last_event());
+	});
+}
+
+#[test]
+fn merge_asset_requires_root() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			Assets::merge_asset(Origin::signed(alice), AssetId::ETH, AssetId::Token(H160::zero()), 10),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Assets::merge_asset(Origin::root(), AssetId::ETH, AssetId::ETH, 10),
+			Error::<Test>::MergeIntoSelf
+		);
+	});
+}
+
+#[test]
+fn noop_transfers_are_refunded() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		let declared = Call::<Test>::transfer(asset_id, bob.clone(), 0.into()).get_dispatch_info().weight;
+		assert_eq!(declared, MockWeightInfo::transfer());
+
+		let post_info = Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 0.into()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::transfer_noop()));
+		assert!(post_info.actual_weight.unwrap() < declared);
+
+		let post_info = Assets::transfer(Origin::signed(alice.clone()), asset_id, alice.clone(), 100.into()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::transfer_noop()));
+		assert!(post_info.actual_weight.unwrap() < declared);
+
+		let post_info = Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 100.into()).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 400.into());
+	});
+}
+
+#[test]
+fn group_total_supply_sums_basket_assets() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::ETH, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::Token(H160::repeat_byte(1)), &alice, 50.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::Token(H160::repeat_byte(2)), &alice, 25.into()));
+
+		assert_eq!(Assets::group_total_supply(0), (150.into(), false));
+		assert_eq!(Assets::group_total_supply(2), (U256::zero(), false));
+	});
+}
+
+#[test]
+fn group_total_supply_saturates_on_overflow() {
+	new_tester().execute_with(|| {
+		TotalIssuance::insert(AssetId::Token(H160::repeat_byte(2)), U256::MAX);
+		TotalIssuance::insert(AssetId::Token(H160::repeat_byte(3)), U256::one());
+
+		assert_eq!(Assets::group_total_supply(1), (U256::MAX, true));
+	});
+}
+
+#[test]
+fn transfer_validator_can_reject_transfers() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &ferdie, 100.into()),
+			Error::<Test>::TransferRejected
+		);
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 100.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 100.into());
+	});
+}
+
+#[test]
+fn transfer_fraction_of_balance() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 501);
+
+		assert_ok!(Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, bob.clone(), 1, 2));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 251.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 250.into());
+
+		assert_ok!(Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, bob.clone(), 3, 3));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 0.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 501.into());
+	});
+}
+
+#[test]
+fn transfer_fraction_rounding_to_zero_is_noop() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 1);
+
+		assert_ok!(Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, bob.clone(), 1, 3));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 1.into());
+		assert_eq!(Balances::<Test>::get(&asset_id, &bob), 0.into());
+	});
+}
+
+#[test]
+fn noop_transfers_emit_no_event() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+		let eth_dest = H160::repeat_byte(2);
+
+		let post_info = Assets::transfer_to_ethereum(Origin::signed(alice.clone()), asset_id, eth_dest, 0.into()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::transfer_noop()));
+
+		let post_info = Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, alice.clone(), 1, 2).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::transfer_noop()));
+
+		assert_ok!(Assets::transfer_and_burn(
+			Origin::root(),
+			alice.clone(),
+			(asset_id, bob.clone(), 0.into()),
+			(asset_id, 0.into()),
+		));
+		assert!(System::events().is_empty());
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 500.into());
+	});
+}
+
+#[test]
+fn transfer_fraction_rejects_invalid_fractions() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 100);
+
+		assert_noop!(
+			Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, bob.clone(), 1, 0),
+			Error::<Test>::InvalidFraction
+		);
+		assert_noop!(
+			Assets::transfer_fraction(Origin::signed(alice.clone()), asset_id, bob.clone(), 3, 2),
+			Error::<Test>::InvalidFraction
+		);
+	});
+}
+
+#[test]
+fn holds_are_tracked_per_reason() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 100.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Governance, &alice, 50.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 20.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 330.into());
+		assert_eq!(Assets::balance_on_hold(asset_id, &alice), 170.into());
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Custody, &alice), 120.into());
+		assert_eq!(TotalIssuance::get(&asset_id), 500.into());
+
+		assert_ok!(Assets::release(asset_id, HoldReason::Custody, &alice, 120.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 450.into());
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Custody, &alice), 0.into());
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Governance, &alice), 50.into());
+
+		assert_ok!(Assets::release(asset_id, HoldReason::Governance, &alice, 50.into()));
+		assert!(!Holds::<Test>::contains_key(&asset_id, &alice));
+	});
+}
+
+#[test]
+fn hold_should_fail_without_free_balance_or_capacity() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		set_balance(asset_id, &alice, 100);
+
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Custody, &alice, 101.into()),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 10.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Governance, &alice, 10.into()));
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Staking, &alice, 10.into()),
+			Error::<Test>::TooManyHolds
+		);
+		assert_noop!(
+			Assets::release(asset_id, HoldReason::Custody, &alice, 11.into()),
+			Error::<Test>::InsufficientHold
+		);
+		assert_noop!(
+			Assets::release(asset_id, HoldReason::Staking, &alice, 1.into()),
+			Error::<Test>::InsufficientHold
+		);
+	});
+}
+
+#[test]
+fn mint_and_lock_is_not_spendable_until_released() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		assert_noop!(
+			Assets::mint_and_lock(Origin::signed(bob.clone()), asset_id, alice.clone(), 100.into(), HoldReason::Custody),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Assets::mint_and_lock(Origin::root(), asset_id, alice.clone(), 100.into(), HoldReason::Custody));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 0.into());
+		assert_eq!(Assets::balance_on_hold(asset_id, &alice), 100.into());
+		assert_eq!(TotalIssuance::get(&asset_id), 100.into());
+		let events: Vec<Event> = System::events().into_iter().map(|record| record.event).collect();
+		assert_eq!(
+			events[events.len() - 2..],
+			[
+				Event::Assets(RawEvent::Minted(asset_id, alice.clone(), 100.into())),
+				Event::Assets(RawEvent::Held(asset_id, HoldReason::Custody, alice.clone(), 100.into())),
+			]
+		);
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 1.into()),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Assets::release(asset_id, HoldReason::Custody, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 100.into()));
+	});
+}
+
+#[test]
+fn mint_and_lock_rolls_back_mint_when_hold_fails() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		set_balance(asset_id, &alice, 10);
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 5.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Governance, &alice, 5.into()));
+
+		assert_noop!(
+			Assets::mint_and_lock(Origin::root(), asset_id, alice.clone(), 100.into(), HoldReason::Staking),
+			Error::<Test>::TooManyHolds
+		);
+		assert_eq!(TotalIssuance::get(&asset_id), 10.into());
+	});
+}
+
+#[test]
+fn largest_holders_returns_top_balances() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+		let dave: AccountId = Keyring::Dave.into();
+
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 300.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &charlie, 200.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::Token(H160::zero()), &dave, 1000.into()));
+
+		assert_eq!(
+			Assets::largest_holders(asset_id, 2),
+			vec![(alice.clone(), 300.into()), (charlie.clone(), 200.into())]
+		);
+		assert_eq!(Assets::largest_holders(asset_id, 10).len(), 3);
+		assert!(Assets::largest_holders(asset_id, 0).is_empty());
+	});
+}
+
+#[test]
+fn partially_frozen_account_can_only_spend_excess() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 300.into(), 0));
+
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 150.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 50.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 1.into()),
+			Error::<Test>::BalanceFrozen
+		);
+		assert_noop!(
+			<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 1.into()),
+			Error::<Test>::BalanceFrozen
+		);
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Custody, &alice, 1.into()),
+			Error::<Test>::BalanceFrozen
+		);
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 0.into(), 1));
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 300.into()));
+		assert_eq!(Balances::<Test>::get(&asset_id, &alice), 0.into());
+	});
+}
+
+#[test]
+fn set_freeze_requires_update_origin() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			Assets::set_freeze(Origin::signed(alice.clone()), AssetId::ETH, alice, 1.into(), 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_freeze_rejects_stale_nonce() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 300.into(), 0));
+		assert_eq!(
+			last_event(),
+			Event::Assets(RawEvent::FreezeSet(asset_id, alice.clone(), 300.into(), 1))
+		);
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 400.into(), 1));
+
+		// A thaw based on the first freeze is stale once the freeze was changed again
+		assert_noop!(
+			Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 0.into(), 1),
+			Error::<Test>::StaleFreezeNonce
+		);
+		assert_eq!(Assets::frozen(asset_id, &alice), 400.into());
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 0.into(), 2));
+		assert_eq!(Assets::frozen(asset_id, &alice), 0.into());
+		assert_eq!(Assets::freeze_nonce(asset_id, &alice), 3);
+	});
+}
+
+#[test]
+fn merge_asset_carries_over_frozen_amounts() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 100.into()));
+		assert_ok!(Assets::set_freeze(Origin::root(), from_id, alice.clone(), 60.into(), 0));
+
+		assert_ok!(Assets::set_freeze(Origin::root(), into_id, alice.clone(), 0.into(), 0));
+
+		assert_ok!(Assets::merge_asset(Origin::root(), from_id, into_id, 10));
+		assert_eq!(Assets::frozen(into_id, &alice), 60.into());
+		assert_eq!(Assets::frozen(from_id, &alice), 0.into());
+		assert_eq!(Assets::freeze_nonce(from_id, &alice), 0);
+		assert_eq!(Assets::freeze_nonce(into_id, &alice), 2);
+
+		// A freeze signed against the nonce from before the merge no longer applies
+		assert_noop!(
+			Assets::set_freeze(Origin::root(), into_id, alice.clone(), 0.into(), 1),
+			Error::<Test>::StaleFreezeNonce
+		);
+	});
+}
+
+#[test]
+fn merge_asset_refuses_held_or_paused_assets() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 100.into()));
+		assert_ok!(Assets::hold(from_id, HoldReason::Custody, &alice, 40.into()));
+
+		assert_noop!(
+			Assets::merge_asset(Origin::root(), from_id, into_id, 10),
+			Error::<Test>::AssetHasHolds
+		);
+		assert_ok!(Assets::release(from_id, HoldReason::Custody, &alice, 40.into()));
+
+		assert_ok!(Assets::pause_asset(Origin::root(), from_id, None));
+		assert_noop!(
+			Assets::merge_asset(Origin::root(), from_id, into_id, 10),
+			Error::<Test>::AssetPaused
+		);
+		assert_ok!(Assets::unpause_asset(Origin::root(), from_id));
+
+		assert_ok!(Assets::pause_asset(Origin::root(), into_id, None));
+		assert_noop!(
+			Assets::merge_asset(Origin::root(), from_id, into_id, 10),
+			Error::<Test>::AssetPaused
+		);
+	});
+}
+
+#[test]
+fn merge_asset_clears_state_of_destroyed_asset() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 100.into()));
+		// Bob has a freeze but no balance, so the merge never visits him
+		assert_ok!(Assets::set_freeze(Origin::root(), from_id, bob.clone(), 10.into(), 0));
+		assert_ok!(Assets::set_rate_limit(Origin::root(), from_id, Some((10, 1000.into()))));
+		assert_ok!(Assets::set_max_supply(Origin::root(), from_id, Some(1000.into())));
+
+		assert_ok!(Assets::merge_asset(Origin::root(), from_id, into_id, 10));
+		assert_eq!(last_event(), Event::Assets(RawEvent::AssetDestroyed(from_id)));
+		assert!(!Frozen::<Test>::contains_key(from_id, &bob));
+		assert!(!FreezeNonces::<Test>::contains_key(from_id, &bob));
+		assert!(!Checkpoints::<Test>::contains_key(from_id, &alice));
+		assert!(!RateLimits::<Test>::contains_key(from_id));
+		assert_eq!(Assets::max_supply(from_id), None);
+		assert_eq!(Assets::balances(into_id, &alice), 100.into());
+	});
+}
+
+#[test]
+fn pallet_conforms_to_multi_asset_contract() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		for asset_id in vec![AssetId::ETH, AssetId::Token(H160::repeat_byte(1))] {
+			conformance::deposit_withdraw_round_trip::<Assets, _>(asset_id, &alice, 500.into());
+			conformance::transfer_preserves_total_issuance::<Assets, _>(asset_id, &alice, &bob, 200.into());
+			conformance::withdraw_beyond_balance_fails::<Assets, _>(asset_id, &alice);
+			conformance::zero_and_self_operations_are_noops::<Assets, _>(asset_id, &bob);
+		}
+	});
+}
+
+#[test]
+fn can_mint_and_can_burn_conform_to_deposit_and_withdraw() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(150.into())));
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 30.into(), 0));
+
+		for amount in vec![0, 1, 50, 51, 70, 71, 101] {
+			conformance::can_deposit_agrees_with_deposit::<Assets, _, _>(Assets::can_mint, asset_id, &alice, amount.into());
+			conformance::can_withdraw_agrees_with_withdraw::<Assets, _, _>(Assets::can_burn, asset_id, &alice, amount.into());
+		}
+
+		// Deposits to a paused asset are queued, so only withdrawals are compared
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, None));
+		assert!(!Assets::can_mint(asset_id, &alice, 1.into()));
+		conformance::can_withdraw_agrees_with_withdraw::<Assets, _, _>(Assets::can_burn, asset_id, &alice, 1.into());
+		assert_eq!(Assets::balances(asset_id, &alice), 100.into());
+	});
+}
+
+#[test]
+fn export_assets_lists_total_issuance() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		let assets = vec![
+			(AssetId::ETH, U256::from(100)),
+			(AssetId::Token(H160::repeat_byte(1)), U256::from(200)),
+			(AssetId::Token(H160::repeat_byte(2)), U256::from(300)),
+		];
+		for (asset_id, amount) in assets.iter() {
+			assert_ok!(<Assets as MultiAsset<_>>::deposit(*asset_id, &alice, *amount));
+		}
+
+		let exported = Assets::export_assets();
+		assert_eq!(exported.len(), assets.len());
+		assert!(assets.iter().all(|entry| exported.contains(entry)));
+
+		let mut paged = Assets::export_assets_paged(0, 2);
+		assert_eq!(paged.len(), 2);
+		paged.extend(Assets::export_assets_paged(2, 2));
+		assert_eq!(paged, exported);
+		assert!(Assets::export_assets_paged(3, 2).is_empty());
+	});
+}
+
+#[test]
+fn do_transfer_weighed_reports_transfer_weight() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		let (result, weight) = Assets::do_transfer_weighed(asset_id, alice.clone(), bob.clone(), 20.into());
+		assert_ok!(result);
+		assert_eq!(weight, 1_000);
+		assert_eq!(weight, Assets::transfer_weight());
+		assert_eq!(Assets::balances(asset_id, &bob), 20.into());
+
+		let (result, weight) = Assets::do_transfer_weighed(asset_id, alice.clone(), alice.clone(), 20.into());
+		assert_ok!(result);
+		assert_eq!(weight, 100);
+	});
+}
+
+#[test]
+fn try_state_detects_supply_mismatch() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 200.into()));
+		assert_ok!(Assets::do_try_state());
+
+		Balances::<Test>::insert(asset_id, &bob, U256::from(1));
+		assert_eq!(
+			Assets::do_try_state(),
+			Err("total issuance does not match the sum of balances")
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_balance_without_total_issuance() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		Balances::<Test>::insert(AssetId::Token(H160::repeat_byte(9)), &alice, U256::from(1));
+		assert_eq!(
+			Assets::do_try_state(),
+			Err("balance exists for an asset without total issuance")
+		);
+	});
+}
+
+#[test]
+fn set_max_supply_caps_deposits() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(150.into())));
+		assert_eq!(last_event(), Event::Assets(RawEvent::MaxSupplyChanged(asset_id, Some(150.into()))));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 50.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()),
+			Error::<Test>::MaxSupplyExceeded
+		);
+
+		// Raising the cap allows further deposits
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(200.into())));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 10.into()));
+
+		// Removing the cap lifts the limit entirely
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, None));
+		assert_eq!(last_event(), Event::Assets(RawEvent::MaxSupplyChanged(asset_id, None)));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1000.into()));
+		assert_eq!(Assets::max_supply(asset_id), None);
+	});
+}
+
+#[test]
+fn set_max_supply_cannot_go_below_total_issuance() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(500.into())));
+
+		// Lowering the cap is fine as long as it stays at or above the total issuance
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(100.into())));
+		assert_noop!(
+			Assets::set_max_supply(Origin::root(), asset_id, Some(99.into())),
+			Error::<Test>::SupplyExceedsNewCap
+		);
+		assert_eq!(Assets::max_supply(asset_id), Some(100.into()));
+	});
+}
+
+#[test]
+fn set_max_supply_requires_update_origin() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			Assets::set_max_supply(Origin::signed(alice), AssetId::ETH, Some(1.into())),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn zero_transfer_does_not_touch_destination() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 0.into()));
+		assert!(!Balances::<Test>::contains_key(asset_id, &bob));
+		assert!(!System::account_exists(&bob));
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, charlie.clone(), 1.into()));
+		assert_eq!(Balances::<Test>::get(asset_id, &charlie), 1.into());
+	});
+}
+
+#[test]
+fn transfer_on_hold_keeps_funds_held_at_destination() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 100.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 300.into()));
+
+		assert_eq!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 120.into()),
+			Ok(120.into())
+		);
+		assert_eq!(
+			last_event(),
+			Event::Assets(RawEvent::HeldTransferred(asset_id, HoldReason::Custody, alice.clone(), bob.clone(), 120.into()))
+		);
+
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Custody, &alice), 180.into());
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Custody, &bob), 120.into());
+		assert_eq!(Assets::balances(asset_id, &alice), 200.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 100.into());
+		assert_eq!(Assets::total_issuance(asset_id), 600.into());
+
+		// Moving the rest of the hold clears it from the source
+		assert_ok!(Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 180.into()));
+		assert!(!Holds::<Test>::contains_key(asset_id, &alice));
+		assert_eq!(Assets::balance_on_hold_for(asset_id, &HoldReason::Custody, &bob), 300.into());
+	});
+}
+
+#[test]
+fn transfer_on_hold_should_fail_without_enough_held() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 100.into()));
+
+		assert_noop!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 101.into()),
+			Error::<Test>::InsufficientHold
+		);
+		assert_noop!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Staking, &alice, &bob, 1.into()),
+			Error::<Test>::InsufficientHold
+		);
+	});
+}
+
+#[test]
+fn transfer_event_is_indexed_by_asset_topic() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 20.into()));
+
+		let record = System::events().pop().expect("Event expected");
+		assert_eq!(record.topics[0], Assets::asset_topic(asset_id));
+		assert_eq!(System::event_topics(&Assets::asset_topic(asset_id)).len(), 1);
+		assert_ne!(Assets::asset_topic(asset_id), Assets::asset_topic(AssetId::ETH));
+	});
+}
+
+#[test]
+fn balance_at_reconstructs_historical_balances() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		System::set_block_number(2);
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		System::set_block_number(5);
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, 200.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 100.into()));
+
+		assert_eq!(Assets::balance_at(asset_id, &alice, 1), Some(0.into()));
+		assert_eq!(Assets::balance_at(asset_id, &alice, 2), Some(500.into()));
+		assert_eq!(Assets::balance_at(asset_id, &alice, 4), Some(500.into()));
+		assert_eq!(Assets::balance_at(asset_id, &alice, 5), Some(200.into()));
+		assert_eq!(Assets::balance_at(asset_id, &alice, 100), Some(200.into()));
+		assert_eq!(Assets::balance_at(asset_id, &bob, 4), Some(0.into()));
+		assert_eq!(Assets::balance_at(asset_id, &bob, 5), Some(200.into()));
+
+		// Changes within one block share a single checkpoint
+		assert_eq!(Checkpoints::<Test>::get(asset_id, &alice).len(), 2);
+	});
+}
+
+#[test]
+fn checkpoints_are_pruned_oldest_first() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		for block in 1..=4 {
+			System::set_block_number(block);
+			assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 10.into()));
+		}
+
+		assert_eq!(Checkpoints::<Test>::get(asset_id, &alice).len(), 3);
+		assert_eq!(Assets::balance_at(asset_id, &alice, 1), None);
+		assert_eq!(Assets::balance_at(asset_id, &alice, 2), Some(20.into()));
+		assert_eq!(Assets::balance_at(asset_id, &alice, 4), Some(40.into()));
+	});
+}
+
+#[test]
+fn transfer_should_raise_balance_overflow_error() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		Balances::<Test>::insert(asset_id, &alice, U256::one());
+		Balances::<Test>::insert(asset_id, &bob, U256::MAX);
+		TotalIssuance::insert(asset_id, U256::MAX);
+
+		assert_noop!(
+			<Assets as MultiAsset<_>>::transfer(asset_id, &alice, &bob, U256::one()),
+			Error::<Test>::BalanceOverflow
+		);
+	});
+}
+
+#[test]
+fn release_should_raise_balance_overflow_error() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 1.into()));
+		Balances::<Test>::insert(asset_id, &alice, U256::MAX);
+
+		assert_noop!(
+			Assets::release(asset_id, HoldReason::Custody, &alice, 1.into()),
+			Error::<Test>::BalanceOverflow
+		);
+	});
+}
+
+#[test]
+fn hold_should_raise_balance_overflow_error() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 2.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 1.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 1.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &bob, 1.into()));
+		Holds::<Test>::insert(asset_id, &alice, BoundedVec::try_from(vec![(HoldReason::Custody, U256::MAX)]).unwrap());
+
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Custody, &alice, 1.into()),
+			Error::<Test>::BalanceOverflow
+		);
+		assert_noop!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Custody, &bob, &alice, 1.into()),
+			Error::<Test>::BalanceOverflow
+		);
+	});
+}
+
+#[test]
+fn merge_asset_should_raise_total_issuance_overflow_error() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 1.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(into_id, &bob, U256::MAX));
+
+		assert_noop!(
+			Assets::merge_asset(Origin::root(), from_id, into_id, 10),
+			Error::<Test>::TotalIssuanceOverflow
+		);
+	});
+}
+
+#[test]
+fn can_mint_reports_whether_deposit_would_succeed() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let unique = AssetId::Token(H160::repeat_byte(0xff));
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert!(Assets::can_mint(asset_id, &alice, 100.into()));
+		assert_eq!(Assets::balances(asset_id, &alice), 0.into());
+		assert_eq!(Assets::total_issuance(asset_id), 0.into());
+
+		// Total issuance overflow
+		TotalIssuance::insert(asset_id, U256::MAX);
+		assert!(!Assets::can_mint(asset_id, &alice, 1.into()));
+		TotalIssuance::remove(asset_id);
+
+		// Maximum supply
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(50.into())));
+		assert!(Assets::can_mint(asset_id, &alice, 50.into()));
+		assert!(!Assets::can_mint(asset_id, &alice, 51.into()));
+
+		// Unique asset supply
+		assert!(Assets::can_mint(unique, &alice, 1.into()));
+		assert!(!Assets::can_mint(unique, &alice, 2.into()));
+	});
+}
+
+#[test]
+fn can_burn_reports_whether_withdrawal_would_succeed() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+
+		assert!(Assets::can_burn(asset_id, &alice, 500.into()));
+		assert_eq!(Assets::balances(asset_id, &alice), 500.into());
+
+		// Insufficient balance
+		assert!(!Assets::can_burn(asset_id, &alice, 501.into()));
+
+		// Frozen balance
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 300.into(), 0));
+		assert!(Assets::can_burn(asset_id, &alice, 200.into()));
+		assert!(!Assets::can_burn(asset_id, &alice, 201.into()));
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 0.into(), 1));
+
+		// Rate limit, which is not consumed by the check itself
+		assert_ok!(Assets::set_rate_limit(Origin::root(), asset_id, Some((10, 100.into()))));
+		assert!(Assets::can_burn(asset_id, &alice, 100.into()));
+		assert!(!Assets::can_burn(asset_id, &alice, 101.into()));
+		assert_eq!(RateLimits::<Test>::get(asset_id).unwrap().spent, U256::zero());
+
+		// Paused asset
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, None));
+		assert!(!Assets::can_burn(asset_id, &alice, 1.into()));
+		assert!(!Assets::can_mint(asset_id, &alice, 1.into()));
+	});
+}
+
+#[test]
+fn can_mint_and_can_burn_do_not_notify_supply_changes() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		SUPPLY_CHANGES.with(|changes| changes.borrow_mut().clear());
+
+		assert!(Assets::can_mint(asset_id, &alice, 100.into()));
+		assert!(Assets::can_burn(asset_id, &alice, 100.into()));
+		assert!(SUPPLY_CHANGES.with(|changes| changes.borrow().is_empty()));
+		assert_eq!(Assets::checkpoints(asset_id, &alice).len(), 1);
+	});
+}
+
+#[test]
+fn on_transfer_hook_observes_completed_transfer() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(Assets::deposit_and_hold(asset_id, HoldReason::Custody, &bob, 10.into()));
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 200.into()));
+
+		// The hook saw both balances updated and could not reenter `transfer` or
+		// `transfer_on_hold`
+		let observed = OBSERVED_TRANSFERS.with(|observed| observed.borrow().clone());
+		assert_eq!(observed, vec![(300.into(), 200.into(), false, false)]);
+		assert_eq!(Assets::balances(asset_id, &alice), 300.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 200.into());
+		assert_eq!(Assets::balance_on_hold(asset_id, &bob), 10.into());
+
+		// The guard is lifted once the hook returns
+		assert_ok!(<Assets as MultiAsset<_>>::transfer(asset_id, &bob, &alice, 100.into()));
+		assert_ok!(Assets::transfer_on_hold(asset_id, HoldReason::Custody, &bob, &alice, 1.into()));
+	});
+}
+
+#[test]
+fn transfer_and_burn_applies_both() {
+	new_tester().execute_with(|| {
+		let collateral = AssetId::Token(H160::repeat_byte(1));
+		let debt = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(collateral, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(debt, &alice, 100.into()));
+
+		assert_ok!(Assets::transfer_and_burn(
+			Origin::root(), alice.clone(), (collateral, bob.clone(), 300.into()), (debt, 100.into())
+		));
+		assert_eq!(Assets::balances(collateral, &alice), 200.into());
+		assert_eq!(Assets::balances(collateral, &bob), 300.into());
+		assert_eq!(Assets::balances(debt, &alice), 0.into());
+		assert_eq!(Assets::total_issuance(debt), 0.into());
+	});
+}
+
+#[test]
+fn transfer_and_burn_rolls_back_transfer_on_burn_shortfall() {
+	new_tester().execute_with(|| {
+		let collateral = AssetId::Token(H160::repeat_byte(1));
+		let debt = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(collateral, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(debt, &alice, 50.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(debt, &bob, 100.into()));
+
+		assert_noop!(
+			Assets::transfer_and_burn(
+				Origin::root(), alice.clone(), (collateral, bob.clone(), 300.into()), (debt, 100.into())
+			),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Assets::balances(collateral, &bob), 0.into());
+	});
+}
+
+#[test]
+fn transfer_and_burn_requires_root() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_noop!(
+			Assets::transfer_and_burn(
+				Origin::signed(alice.clone()), alice, (AssetId::ETH, bob, 1.into()), (AssetId::ETH, 1.into())
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn clear_account_removes_zero_balance_entry() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 100.into()));
+		assert!(Balances::<Test>::contains_key(asset_id, &alice));
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 10.into(), 0));
+		assert!(Checkpoints::<Test>::contains_key(asset_id, &alice));
+
+		assert_ok!(Assets::clear_account(Origin::signed(alice.clone()), asset_id, alice.clone()));
+		assert!(!Balances::<Test>::contains_key(asset_id, &alice));
+		assert!(!Frozen::<Test>::contains_key(asset_id, &alice));
+		assert!(!FreezeNonces::<Test>::contains_key(asset_id, &alice));
+		assert!(!Checkpoints::<Test>::contains_key(asset_id, &alice));
+		assert_eq!(last_event(), Event::Assets(RawEvent::AccountCleared(asset_id, alice.clone())));
+
+		Balances::<Test>::insert(asset_id, &bob, U256::zero());
+		assert_ok!(Assets::clear_account(Origin::root(), asset_id, bob.clone()));
+		assert!(!Balances::<Test>::contains_key(asset_id, &bob));
+	});
+}
+
+#[test]
+fn clear_account_should_fail_for_funded_or_foreign_account() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		Balances::<Test>::insert(asset_id, &bob, U256::zero());
+
+		assert_noop!(
+			Assets::clear_account(Origin::signed(alice.clone()), asset_id, alice.clone()),
+			Error::<Test>::BalanceNotZero
+		);
+		assert_noop!(
+			Assets::clear_account(Origin::signed(alice.clone()), asset_id, bob.clone()),
+			DispatchError::BadOrigin
+		);
+
+		// A zero free balance with funds on hold is still in use
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 100.into()));
+		assert_noop!(
+			Assets::clear_account(Origin::signed(alice.clone()), asset_id, alice.clone()),
+			Error::<Test>::AccountHasHolds
+		);
+	});
+}
+
+#[test]
+fn fraction_of_rounds_according_to_mode() {
+	let fraction_of = |amount: u32, numerator, denominator, rounding| {
+		Assets::fraction_of(amount.into(), numerator, denominator, rounding).unwrap()
+	};
+
+	// 7 / 3 = 2.33
+	assert_eq!(fraction_of(7, 1, 3, RoundingMode::Down), 2.into());
+	assert_eq!(fraction_of(7, 1, 3, RoundingMode::Up), 3.into());
+	assert_eq!(fraction_of(7, 1, 3, RoundingMode::HalfUp), 2.into());
+
+	// 14 / 3 = 4.67
+	assert_eq!(fraction_of(7, 2, 3, RoundingMode::Down), 4.into());
+	assert_eq!(fraction_of(7, 2, 3, RoundingMode::Up), 5.into());
+	assert_eq!(fraction_of(7, 2, 3, RoundingMode::HalfUp), 5.into());
+
+	// 5 / 2 = 2.5
+	assert_eq!(fraction_of(5, 1, 2, RoundingMode::Down), 2.into());
+	assert_eq!(fraction_of(5, 1, 2, RoundingMode::HalfUp), 3.into());
+
+	// Exact divisions are never rounded
+	assert_eq!(fraction_of(9, 1, 3, RoundingMode::Up), 3.into());
+	assert_eq!(fraction_of(9, 1, 3, RoundingMode::HalfUp), 3.into());
+	assert_eq!(RoundingMode::default(), RoundingMode::Down);
+}
+
+#[test]
+fn burn_from_respects_precision_and_fortitude() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 100.into()));
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 300.into(), 0));
+		// Free balance is 400, of which 300 are frozen
+
+		assert_noop!(
+			Assets::burn_from(asset_id, &alice, 150.into(), Precision::Exact, Fortitude::Polite),
+			Error::<Test>::BalanceFrozen
+		);
+		assert_eq!(Assets::burn_from(asset_id, &alice, 50.into(), Precision::Exact, Fortitude::Polite), Ok(50.into()));
+		assert_eq!(
+			Assets::burn_from(asset_id, &alice, 150.into(), Precision::BestEffort, Fortitude::Polite),
+			Ok(50.into())
+		);
+		assert_eq!(Assets::balances(asset_id, &alice), 300.into());
+
+		// Forcing burns through the freeze, but never touches funds on hold
+		assert_eq!(Assets::burn_from(asset_id, &alice, 100.into(), Precision::Exact, Fortitude::Force), Ok(100.into()));
+		assert_noop!(
+			Assets::burn_from(asset_id, &alice, 201.into(), Precision::Exact, Fortitude::Force),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(
+			Assets::burn_from(asset_id, &alice, 1000.into(), Precision::BestEffort, Fortitude::Force),
+			Ok(200.into())
+		);
+
+		assert_eq!(Assets::balances(asset_id, &alice), 0.into());
+		assert_eq!(Assets::balance_on_hold(asset_id, &alice), 100.into());
+		assert_eq!(Assets::total_issuance(asset_id), 100.into());
+	});
+}
+
+#[test]
+fn mint_requires_mint_origin() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::mint(Origin::root(), asset_id, alice.clone(), 100.into()));
+		assert_eq!(last_event(), Event::Assets(RawEvent::Minted(asset_id, alice.clone(), 100.into())));
+		assert_eq!(Assets::balances(asset_id, &alice), 100.into());
+		assert_eq!(Assets::total_issuance(asset_id), 100.into());
+
+		assert_noop!(
+			Assets::mint(Origin::signed(alice.clone()), asset_id, alice.clone(), 100.into()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn merge_asset_refunds_weight_for_unmerged_accounts() {
+	new_tester().execute_with(|| {
+		let from_id = AssetId::Token(H160::repeat_byte(1));
+		let into_id = AssetId::Token(H160::repeat_byte(2));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(from_id, &bob, 100.into()));
+
+		let declared = Call::<Test>::merge_asset(from_id, into_id, 10).get_dispatch_info().weight;
+		assert_eq!(declared, MockWeightInfo::merge_asset(10));
+
+		let post_info = Assets::merge_asset(Origin::root(), from_id, into_id, 10).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::merge_asset(2)));
+		assert!(MockWeightInfo::merge_asset(2) < declared);
+		assert_eq!(last_event(), Event::Assets(RawEvent::AssetDestroyed(from_id)));
+	});
+}
+
+#[test]
+fn holds_move_reducible_total_issuance() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 500.into()));
+		assert_eq!(Assets::reducible_total_issuance(asset_id), 1000.into());
+
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 200.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Staking, &bob, 100.into()));
+		assert_eq!(TotalHeld::get(asset_id), 300.into());
+		assert_eq!(Assets::reducible_total_issuance(asset_id), 700.into());
+
+		// Moving funds between holds leaves the aggregate unchanged
+		assert_ok!(Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 50.into()));
+		assert_eq!(Assets::reducible_total_issuance(asset_id), 700.into());
+
+		assert_ok!(Assets::release(asset_id, HoldReason::Custody, &bob, 50.into()));
+		assert_eq!(Assets::reducible_total_issuance(asset_id), 750.into());
+		assert_eq!(Assets::total_issuance(asset_id), 1000.into());
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_multi_moves_several_assets() {
+	new_tester().execute_with(|| {
+		let token = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::ETH, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(token, &alice, 500.into()));
+
+		assert_ok!(Assets::transfer_multi(Origin::signed(alice.clone()), batch(vec![
+			(AssetId::ETH, bob.clone(), 100.into()),
+			(token, charlie.clone(), 200.into()),
+			(token, bob.clone(), 50.into()),
+		])));
+		assert_eq!(Assets::balances(AssetId::ETH, &alice), 400.into());
+		assert_eq!(Assets::balances(AssetId::ETH, &bob), 100.into());
+		assert_eq!(Assets::balances(token, &alice), 250.into());
+		assert_eq!(Assets::balances(token, &charlie), 200.into());
+		assert_eq!(Assets::balances(token, &bob), 50.into());
+	});
+}
+
+#[test]
+fn transfer_multi_rolls_back_all_transfers_on_failure() {
+	new_tester().execute_with(|| {
+		let token = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::ETH, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(token, &alice, 100.into()));
+
+		assert_noop!(
+			Assets::transfer_multi(Origin::signed(alice.clone()), batch(vec![
+				(AssetId::ETH, bob.clone(), 100.into()),
+				(token, bob.clone(), 101.into()),
+			])),
+			Error::<Test>::InsufficientBalance
+		);
+
+		let oversized = vec![(AssetId::ETH, bob.clone(), U256::one()); 4];
+		assert!(BoundedVec::<_, MaxBatch>::try_from(oversized.clone()).is_err());
+		assert!(BoundedVec::<(AssetId, AccountId, U256), MaxBatch>::decode(&mut &oversized.encode()[..]).is_err());
+	});
+}
+
+#[test]
+fn global_pause_halts_all_assets() {
+	new_tester().execute_with(|| {
+		let token = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(AssetId::ETH, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(token, &alice, 500.into()));
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), true));
+		assert_eq!(last_event(), Event::Assets(RawEvent::GlobalPauseSet(true)));
+		for asset_id in vec![AssetId::ETH, token] {
+			assert_noop!(
+				Assets::mint(Origin::root(), asset_id, alice.clone(), 1.into()),
+				Error::<Test>::GloballyPaused
+			);
+			assert_noop!(
+				<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 1.into()),
+				Error::<Test>::GloballyPaused
+			);
+			assert_noop!(
+				Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 1.into()),
+				Error::<Test>::GloballyPaused
+			);
+		}
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), false));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(token, &alice, 1.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(token, &alice, 1.into()));
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), AssetId::ETH, bob.clone(), 1.into()));
+	});
+}
+
+#[test]
+fn set_global_pause_requires_update_origin() {
+	new_tester().execute_with(|| {
+		let alice: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			Assets::set_global_pause(Origin::signed(alice), true),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn swap_balances_exchanges_free_balances() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 200.into()));
+
+		assert_ok!(Assets::swap_balances(asset_id, &alice, &bob));
+		assert_eq!(Assets::balances(asset_id, &alice), 200.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 500.into());
+		assert_eq!(Assets::balance_at(asset_id, &alice, 1), Some(200.into()));
+		assert_eq!(Assets::balance_at(asset_id, &bob, 1), Some(500.into()));
+
+		// Both sides are reported like transfers, and the hook could not reenter `transfer`
+		let observed = OBSERVED_TRANSFERS.with(|observed| observed.borrow().clone());
+		assert_eq!(observed, vec![(200.into(), 500.into(), false, false), (500.into(), 200.into(), false, false)]);
+		let events: Vec<Event> = System::events().into_iter().map(|record| record.event).collect();
+		assert_eq!(
+			events[events.len() - 2..],
+			[
+				Event::Assets(RawEvent::TransferredCompact(asset_id, alice.clone(), bob.clone(), Compact(500))),
+				Event::Assets(RawEvent::TransferredCompact(asset_id, bob.clone(), alice.clone(), Compact(200))),
+			]
+		);
+
+		// Swapping with an account without a balance moves the whole balance over
+		assert_ok!(Assets::swap_balances(asset_id, &bob, &charlie));
+		assert_eq!(Assets::balances(asset_id, &bob), 0.into());
+		assert_eq!(Assets::balances(asset_id, &charlie), 500.into());
+		assert!(!Balances::<Test>::contains_key(asset_id, &bob));
+		assert_eq!(
+			last_event(),
+			Event::Assets(RawEvent::TransferredCompact(asset_id, bob.clone(), charlie.clone(), Compact(500)))
+		);
+
+		assert_eq!(Assets::total_issuance(asset_id), 700.into());
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+fn swap_balances_is_checked_like_a_transfer() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let ferdie: AccountId = Keyring::Ferdie.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 500.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 200.into()));
+
+		// Alice's frozen amount would exceed the balance she gets in exchange
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 300.into(), 0));
+		assert_noop!(Assets::swap_balances(asset_id, &alice, &bob), Error::<Test>::BalanceFrozen);
+		assert_noop!(Assets::swap_balances(asset_id, &bob, &alice), Error::<Test>::BalanceFrozen);
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 200.into(), 1));
+		assert_ok!(Assets::swap_balances(asset_id, &alice, &bob));
+		assert_eq!(Assets::balances(asset_id, &alice), 200.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 500.into());
+
+		assert_noop!(Assets::swap_balances(asset_id, &alice, &ferdie), Error::<Test>::TransferRejected);
+
+		// Both sides count against the rate limit
+		assert_ok!(Assets::set_rate_limit(Origin::root(), asset_id, Some((10, 600.into()))));
+		assert_noop!(Assets::swap_balances(asset_id, &alice, &bob), Error::<Test>::RateLimited);
+		assert_ok!(Assets::set_rate_limit(Origin::root(), asset_id, Some((10, 700.into()))));
+		assert_ok!(Assets::swap_balances(asset_id, &alice, &bob));
+		assert_eq!(RateLimits::<Test>::get(asset_id).unwrap().spent, 700.into());
+	});
+}
+
+#[test]
+fn dry_run_transfer_reports_outcome_without_changes() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_noop!(
+			Assets::transfer_dry_run(Origin::signed(alice.clone()), asset_id, bob.clone(), 600.into()),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 600.into()),
+			Error::<Test>::InsufficientBalance
+		);
+
+		let events = System::events().len();
+		assert_ok!(Assets::transfer_dry_run(Origin::signed(alice.clone()), asset_id, bob.clone(), 200.into()));
+		assert_eq!(Assets::balances(asset_id, &alice), 500.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 0.into());
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 200.into()));
+		assert_eq!(Assets::balances(asset_id, &bob), 200.into());
+	});
+}
+
+#[test]
+fn dry_run_transfer_is_free_and_reports_weight_used() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		set_balance(asset_id, &alice, 500);
+
+		let info = Call::<Test>::transfer_dry_run(asset_id, bob.clone(), 200.into()).get_dispatch_info();
+		assert_eq!(info.weight, MockWeightInfo::transfer());
+		assert_eq!(info.pays_fee, Pays::No);
+
+		let post_info = Assets::transfer_dry_run(Origin::signed(alice.clone()), asset_id, bob.clone(), 200.into()).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+
+		let post_info = Assets::transfer_dry_run(Origin::signed(alice.clone()), asset_id, bob.clone(), 0.into()).unwrap();
+		assert_eq!(post_info.actual_weight, Some(MockWeightInfo::transfer_noop()));
+	});
+}
+
+#[test]
+fn mint_capped_enforces_caps() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 500.into(), 1000.into()));
+		assert_eq!(last_event(), Event::Assets(RawEvent::Minted(asset_id, alice.clone(), 500.into())));
+		assert_ok!(Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 1000.into(), 1000.into()));
+		assert_eq!(Assets::balances(asset_id, &alice), 1500.into());
+
+		assert_noop!(
+			Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 101.into(), 100.into()),
+			Error::<Test>::MintTooLarge
+		);
+		assert_noop!(
+			Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 1001.into(), 1001.into()),
+			Error::<Test>::MintTooLarge
+		);
+		assert_noop!(
+			Assets::mint_capped(Origin::signed(alice.clone()), asset_id, alice.clone(), 1.into(), 1.into()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn mint_with_nonce_rejects_replays() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 100.into(), 7));
+		assert_noop!(
+			Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 100.into(), 7),
+			Error::<Test>::DuplicateMint
+		);
+		assert_eq!(Assets::total_issuance(asset_id), 100.into());
+
+		// Nonces are tracked per asset
+		let token = AssetId::Token(H160::repeat_byte(1));
+		assert_ok!(Assets::mint_with_nonce(Origin::root(), token, alice.clone(), 100.into(), 7));
+		assert_ok!(Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 100.into(), 8));
+		assert_eq!(Assets::total_issuance(asset_id), 200.into());
+	});
+}
+
+#[test]
+fn mint_with_nonce_rejects_zero_amount_without_using_nonce() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_noop!(
+			Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 0.into(), 7),
+			Error::<Test>::ZeroAmount
+		);
+		assert!(!ProcessedNonces::contains_key(asset_id, 7));
+
+		assert_ok!(Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 100.into(), 7));
+		assert_eq!(Assets::total_issuance(asset_id), 100.into());
+	});
+}
+
+#[test]
+fn is_frozen_reflects_account_and_global_freezes() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert!(!<Assets as MultiAsset<_>>::is_frozen(asset_id, &alice));
+
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 100.into(), 0));
+		assert!(<Assets as MultiAsset<_>>::is_frozen(asset_id, &alice));
+		assert!(!<Assets as MultiAsset<_>>::is_frozen(asset_id, &bob));
+		assert!(!<Assets as MultiAsset<_>>::is_frozen(AssetId::Token(H160::repeat_byte(1)), &alice));
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), true));
+		assert!(<Assets as MultiAsset<_>>::is_frozen(asset_id, &bob));
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), false));
+		assert_ok!(Assets::set_freeze(Origin::root(), asset_id, alice.clone(), 0.into(), 1));
+		assert!(!Assets::is_frozen(asset_id, &alice));
+	});
+}
+
+#[test]
+fn transfer_event_is_indexed_by_account_topics() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+		set_balance(asset_id, &alice, 500);
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 20.into()));
+		assert_ok!(Assets::transfer(Origin::signed(bob.clone()), asset_id, charlie.clone(), 10.into()));
+
+		let record = System::events().pop().expect("Event expected");
+		assert_eq!(
+			record.topics,
+			vec![Assets::asset_topic(asset_id), Assets::account_topic(&bob), Assets::account_topic(&charlie)]
+		);
+		assert_eq!(System::event_topics(&Assets::account_topic(&alice)).len(), 1);
+		assert_eq!(System::event_topics(&Assets::account_topic(&bob)).len(), 2);
+		assert_eq!(System::event_topics(&Assets::account_topic(&charlie)).len(), 1);
+	});
+}
+
+#[test]
+fn supply_changes_are_reported_on_mint_and_burn() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::mint(Origin::root(), asset_id, alice.clone(), 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 30.into()));
+		// Failed and zero-amount burns leave the supply unchanged and are not reported
+		assert_noop!(
+			<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 100.into()),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 0.into()));
+
+		let changes = SUPPLY_CHANGES.with(|changes| changes.borrow().clone());
+		assert_eq!(changes, vec![
+			(asset_id, 0.into(), 100.into()),
+			(asset_id, 100.into(), 70.into()),
+		]);
+	});
+}
+
+#[test]
+fn asset_pause_expires_at_its_block() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let other = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, Some(5)));
+		assert_eq!(last_event(), Event::Assets(RawEvent::AssetPaused(asset_id, Some(5))));
+		assert!(Assets::is_asset_paused(asset_id));
+		assert!(!Assets::is_asset_paused(other));
+
+		System::set_block_number(4);
+		assert_noop!(
+			Assets::mint(Origin::root(), asset_id, alice.clone(), 1.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert_noop!(
+			<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 1.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert_noop!(
+			Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 1.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert!(<Assets as MultiAsset<_>>::is_frozen(asset_id, &bob));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(other, &alice, 1.into()));
+
+		System::set_block_number(5);
+		assert!(!Assets::is_asset_paused(asset_id));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::withdraw(asset_id, &alice, 1.into()));
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 1.into()));
+	});
+}
+
+#[test]
+fn asset_pause_without_expiry_lasts_until_lifted() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, None));
+		System::set_block_number(1_000_000);
+		assert_noop!(
+			Assets::mint(Origin::root(), asset_id, alice.clone(), 1.into()),
+			Error::<Test>::AssetPaused
+		);
+
+		assert_noop!(
+			Assets::unpause_asset(Origin::signed(alice.clone()), asset_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Assets::unpause_asset(Origin::root(), asset_id));
+		assert_eq!(last_event(), Event::Assets(RawEvent::AssetUnpaused(asset_id)));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 1.into()));
+	});
+}
+
+#[test]
+fn deposits_to_paused_asset_are_queued_up_to_max_pending() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, None));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 10.into()));
+		assert_eq!(last_event(), Event::Assets(RawEvent::DepositQueued(asset_id, alice.clone(), 10.into())));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 20.into()));
+		assert_noop!(
+			<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 30.into()),
+			Error::<Test>::TooManyPendingDeposits
+		);
+
+		assert_eq!(Assets::pending_deposits(asset_id).to_vec(), vec![(alice.clone(), 10.into()), (bob.clone(), 20.into())]);
+		assert_eq!(Assets::balances(asset_id, &alice), 0.into());
+		assert_eq!(Assets::total_issuance(asset_id), 0.into());
+
+		// Nothing is replayed while the asset stays paused
+		Assets::on_initialize(2);
+		assert!(PendingDeposits::<Test>::contains_key(asset_id));
+		assert_eq!(Assets::total_issuance(asset_id), 0.into());
+	});
+}
+
+#[test]
+fn queued_deposits_are_replayed_once_unpaused() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let token = AssetId::Token(H160::repeat_byte(1));
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(25.into())));
+
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, Some(3)));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 10.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 20.into()));
+		assert_ok!(Assets::set_global_pause(Origin::root(), true));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(token, &alice, 5.into()));
+
+		System::set_block_number(3);
+		Assets::on_initialize(3);
+		assert!(PendingDeposits::<Test>::contains_key(asset_id));
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), false));
+		System::reset_events();
+		Assets::on_initialize(4);
+		assert!(!PendingDeposits::<Test>::contains_key(asset_id));
+		assert!(!PendingDeposits::<Test>::contains_key(token));
+		assert_eq!(Assets::balances(asset_id, &alice), 10.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 0.into());
+		assert_eq!(Assets::total_issuance(asset_id), 10.into());
+		assert_eq!(Assets::balances(token, &alice), 5.into());
+
+		let events: Vec<_> = System::events().into_iter().map(|record| record.event).collect();
+		assert!(events.contains(&Event::Assets(RawEvent::DepositReplayed(asset_id, alice.clone(), 10.into()))));
+		assert!(events.contains(&Event::Assets(RawEvent::DepositReplayFailed(asset_id, bob.clone(), 20.into()))));
+		assert!(events.contains(&Event::Assets(RawEvent::DepositReplayed(token, alice.clone(), 5.into()))));
+	});
+}
+
+#[test]
+fn mint_batch_airdrops_to_every_recipient() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		let charlie: AccountId = Keyring::Charlie.into();
+
+		assert_noop!(
+			Assets::mint_batch(Origin::signed(alice.clone()), asset_id, batch(vec![(bob.clone(), 10.into())])),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Assets::mint_batch(Origin::root(), asset_id, batch(vec![
+			(alice.clone(), 10.into()),
+			(bob.clone(), 0.into()),
+			(charlie.clone(), 20.into()),
+		])));
+		assert_eq!(Assets::balances(asset_id, &alice), 10.into());
+		assert!(!Balances::<Test>::contains_key(asset_id, &bob));
+		assert_eq!(Assets::balances(asset_id, &charlie), 20.into());
+		assert_eq!(Assets::total_issuance(asset_id), 30.into());
+		assert_eq!(last_event(), Event::Assets(RawEvent::Minted(asset_id, charlie.clone(), 20.into())));
+
+		let oversized = vec![(alice.clone(), U256::one()); 4];
+		assert!(BoundedVec::<_, MaxBatch>::try_from(oversized.clone()).is_err());
+		assert!(BoundedVec::<(AccountId, U256), MaxBatch>::decode(&mut &oversized.encode()[..]).is_err());
+	});
+}
+
+#[test]
+fn mint_batch_rolls_back_when_an_item_exceeds_the_cap() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(100.into())));
+
+		assert_noop!(
+			Assets::mint_batch(Origin::root(), asset_id, batch(vec![
+				(alice.clone(), 60.into()),
+				(bob.clone(), 50.into()),
+			])),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_eq!(Assets::balances(asset_id, &alice), 0.into());
+		assert_eq!(Assets::total_issuance(asset_id), 0.into());
+	});
+}
+
+#[test]
+fn transfer_requires_the_transfer_origin() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 100.into()));
+		TRANSFER_WHITELIST.with(|whitelist| *whitelist.borrow_mut() = Some(vec![alice.clone()]));
+
+		assert_ok!(Assets::transfer(Origin::signed(alice.clone()), asset_id, bob.clone(), 10.into()));
+		assert_noop!(
+			Assets::transfer(Origin::signed(bob.clone()), asset_id, alice.clone(), 10.into()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Assets::transfer(Origin::root(), asset_id, alice.clone(), 10.into()),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(Assets::balances(asset_id, &alice), 90.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 110.into());
+	});
+}
+
+#[test]
+fn every_signed_transfer_call_requires_the_transfer_origin() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &bob, 100.into()));
+		TRANSFER_WHITELIST.with(|whitelist| *whitelist.borrow_mut() = Some(vec![alice.clone()]));
+
+		assert_noop!(
+			Assets::transfer_fraction(Origin::signed(bob.clone()), asset_id, alice.clone(), 1, 2),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Assets::transfer_multi(Origin::signed(bob.clone()), batch(vec![(asset_id, alice.clone(), 10.into())])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Assets::transfer_to_ethereum(Origin::signed(bob.clone()), asset_id, H160::repeat_byte(2), 10.into()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Assets::transfer_dry_run(Origin::signed(bob.clone()), asset_id, alice.clone(), 10.into()),
+			DispatchError::BadOrigin
+		);
+
+		TRANSFER_WHITELIST.with(|whitelist| *whitelist.borrow_mut() = Some(vec![bob.clone()]));
+		assert_ok!(Assets::transfer_fraction(Origin::signed(bob.clone()), asset_id, alice.clone(), 1, 2));
+		assert_ok!(Assets::transfer_multi(Origin::signed(bob.clone()), batch(vec![(asset_id, alice.clone(), 10.into())])));
+		assert_ok!(Assets::transfer_to_ethereum(Origin::signed(bob.clone()), asset_id, H160::repeat_byte(2), 10.into()));
+		assert_eq!(Assets::balances(asset_id, &bob), 30.into());
+	});
+}
+
+#[test]
+fn remaining_mintable_is_the_headroom_below_the_cap() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 60.into()));
+		assert_eq!(Assets::remaining_mintable(asset_id), None);
+
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(100.into())));
+		assert_eq!(Assets::remaining_mintable(asset_id), Some(40.into()));
+
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 40.into()));
+		assert_eq!(Assets::remaining_mintable(asset_id), Some(0.into()));
+	});
+}
+
+#[test]
+fn net_zero_adjust_conserves_total_issuance() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		// The asset is at its cap, which a net zero adjustment never exceeds
+		assert_ok!(Assets::set_max_supply(Origin::root(), asset_id, Some(100.into())));
+
+		SUPPLY_CHANGES.with(|changes| changes.borrow_mut().clear());
+
+		assert_ok!(Assets::net_zero_adjust(asset_id, &bob, &alice, 30.into()));
+		assert_eq!(Assets::balances(asset_id, &alice), 70.into());
+		assert_eq!(Assets::balances(asset_id, &bob), 30.into());
+		assert_eq!(Assets::total_issuance(asset_id), 100.into());
+		// The supply never changed, so the hook is not notified of a transient dip
+		assert!(SUPPLY_CHANGES.with(|changes| changes.borrow().is_empty()));
+		let events: Vec<Event> = System::events().into_iter().map(|record| record.event).collect();
+		assert_eq!(
+			events[events.len() - 2..],
+			[
+				Event::Assets(RawEvent::Burned(asset_id, alice.clone(), 30.into())),
+				Event::Assets(RawEvent::Minted(asset_id, bob.clone(), 30.into())),
+			]
+		);
+
+		assert_noop!(
+			Assets::net_zero_adjust(asset_id, &alice, &bob, 31.into()),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn holds_cannot_change_while_paused() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+		assert_ok!(<Assets as MultiAsset<_>>::deposit(asset_id, &alice, 100.into()));
+		assert_ok!(Assets::hold(asset_id, HoldReason::Custody, &alice, 50.into()));
+
+		assert_ok!(Assets::set_global_pause(Origin::root(), true));
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Custody, &alice, 10.into()),
+			Error::<Test>::GloballyPaused
+		);
+		assert_noop!(
+			Assets::release(asset_id, HoldReason::Custody, &alice, 10.into()),
+			Error::<Test>::GloballyPaused
+		);
+		assert_noop!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 10.into()),
+			Error::<Test>::GloballyPaused
+		);
+		assert_ok!(Assets::set_global_pause(Origin::root(), false));
+
+		assert_ok!(Assets::pause_asset(Origin::root(), asset_id, None));
+		assert_noop!(
+			Assets::hold(asset_id, HoldReason::Custody, &alice, 10.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert_noop!(
+			Assets::release(asset_id, HoldReason::Custody, &alice, 10.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert_noop!(
+			Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 10.into()),
+			Error::<Test>::AssetPaused
+		);
+		assert_ok!(Assets::unpause_asset(Origin::root(), asset_id));
+
+		assert_ok!(Assets::transfer_on_hold(asset_id, HoldReason::Custody, &alice, &bob, 10.into()));
+		assert_eq!(Assets::balance_on_hold(asset_id, &bob), 10.into());
+	});
+}
+
+#[test]
+fn mint_events_are_indexed_by_asset_and_beneficiary() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+		let bob: AccountId = Keyring::Bob.into();
+
+		assert_ok!(Assets::mint(Origin::root(), asset_id, alice.clone(), 10.into()));
+		assert_ok!(Assets::mint_with_nonce(Origin::root(), asset_id, alice.clone(), 10.into(), 1));
+		assert_ok!(Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 10.into(), 10.into()));
+		assert_ok!(Assets::mint_batch(Origin::root(), asset_id, batch(vec![(bob.clone(), 10.into())])));
+
+		let record = System::events().pop().expect("Event expected");
+		assert_eq!(record.event, Event::Assets(RawEvent::Minted(asset_id, bob.clone(), 10.into())));
+		assert_eq!(record.topics, vec![Assets::asset_topic(asset_id), Assets::account_topic(&bob)]);
+		assert_eq!(System::event_topics(&Assets::asset_topic(asset_id)).len(), 4);
+		assert_eq!(System::event_topics(&Assets::account_topic(&alice)).len(), 3);
+	});
+}
+
+#[test]
+fn zero_amount_mints_emit_no_event() {
+	new_tester().execute_with(|| {
+		let asset_id = AssetId::ETH;
+		let alice: AccountId = Keyring::Alice.into();
+
+		assert_ok!(Assets::mint(Origin::root(), asset_id, alice.clone(), 0.into()));
+		assert_ok!(Assets::mint_capped(Origin::root(), asset_id, alice.clone(), 0.into(), 10.into()));
+		assert_ok!(Assets::mint_batch(Origin::root(), asset_id, batch(vec![(alice.clone(), 0.into())])));
+
+		assert!(System::events().is_empty());
+		assert!(SUPPLY_CHANGES.with(|changes| changes.borrow().is_empty()));
+		assert_eq!(Assets::total_issuance(asset_id), 0.into());
+	});
+}
@@ -67,6 +67,22 @@ impl system::Config for Test {
 
 impl snowbridge_assets::Config for Test {
 	type Event = Event;
+	type UniqueAssets = ();
+	type EthereumAccountDerivation = ();
+	type UpdateOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type BasketGroups = ();
+	type TransferValidator = ();
+	type HoldReason = ();
+	type MaxHolds = ();
+	type EnableCheckpoints = ();
+	type MaxCheckpoints = ();
+	type OnTransfer = ();
+	type MintOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxBatch = ();
+	type MaxSingleMint = ();
+	type OnSupplyChange = ();
+	type TransferOrigin = frame_system::EnsureSigned<Self::AccountId>;
+	type MaxPending = ();
 	type WeightInfo = ();
 }
 
@@ -19,6 +19,17 @@ pub trait MultiAsset<AccountId>
 
 	fn balance(asset_id: AssetId, who: &AccountId) -> U256;
 
+	/// The minimum balance an account must hold of an asset.
+	fn minimum_balance(_asset_id: AssetId) -> U256 {
+		U256::zero()
+	}
+
+	/// Whether any part of the balance of `who` is currently unspendable, either because the
+	/// account is frozen or because the asset, or every asset, is paused.
+	fn is_frozen(_asset_id: AssetId, _who: &AccountId) -> bool {
+		false
+	}
+
 	fn transfer(
 		asset_id: AssetId,
 		from: &AccountId,
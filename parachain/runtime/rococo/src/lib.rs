@@ -575,8 +575,32 @@ impl ethereum_light_client::Config for Runtime {
 	type WeightInfo = weights::ethereum_light_client_weights::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxHolds: u32 = 8;
+	pub const MaxBatch: u32 = 32;
+	pub const MaxPending: u32 = 64;
+	// One million whole units of an asset with 18 decimals
+	pub MaxSingleMint: U256 = U256::from(1_000_000u128 * 1_000_000_000_000_000_000);
+}
+
 impl assets::Config for Runtime {
 	type Event = Event;
+	type UniqueAssets = ();
+	type EthereumAccountDerivation = assets::HashedEthereumAccount<AccountId>;
+	type UpdateOrigin = EnsureRootOrHalfLocalCouncil;
+	type BasketGroups = ();
+	type TransferValidator = ();
+	type HoldReason = [u8; 8];
+	type MaxHolds = MaxHolds;
+	type EnableCheckpoints = ();
+	type MaxCheckpoints = ();
+	type OnTransfer = ();
+	type MintOrigin = EnsureRoot<AccountId>;
+	type MaxBatch = MaxBatch;
+	type MaxSingleMint = MaxSingleMint;
+	type OnSupplyChange = ();
+	type TransferOrigin = frame_system::EnsureSigned<AccountId>;
+	type MaxPending = MaxPending;
 	type WeightInfo = weights::assets_weights::WeightInfo<Runtime>;
 }
 
@@ -714,6 +738,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	assets::CheckTryState<Runtime>,
 >;
 
 impl_runtime_apis! {
@@ -817,6 +842,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl assets_runtime_api::AssetsApi<Block, AccountId, BlockNumber> for Runtime {
+		fn group_total_supply(group: u32) -> (U256, bool) {
+			Assets::group_total_supply(group)
+		}
+		fn export_assets_paged(start: u32, count: u32) -> Vec<(AssetId, U256)> {
+			Assets::export_assets_paged(start, count)
+		}
+		fn reducible_total_issuance(asset_id: AssetId) -> U256 {
+			Assets::reducible_total_issuance(asset_id)
+		}
+		fn remaining_mintable(asset_id: AssetId) -> Option<U256> {
+			Assets::remaining_mintable(asset_id)
+		}
+		fn balance_at(asset_id: AssetId, who: AccountId, block: BlockNumber) -> Option<U256> {
+			Assets::balance_at(asset_id, &who, block)
+		}
+		fn largest_holders(asset_id: AssetId, n: u32) -> Vec<(AccountId, U256)> {
+			Assets::largest_holders(asset_id, n)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
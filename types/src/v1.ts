@@ -68,6 +68,13 @@ export const types: RegistryTypes = {
       Token: "H160",
     },
   },
+  RateLimit: {
+    windowBlocks: "BlockNumber",
+    maxAmount: "U256",
+    spent: "U256",
+    windowStart: "BlockNumber",
+  },
+  HoldReason: "[u8; 8]",
   TokenId: "u128",
   TokenData: {
     tokenContract: "H160",